@@ -0,0 +1,122 @@
+//! Per-wallet address book stored in a `<wallet>.contacts` file next to
+//! the wallet file.
+
+use crate::imports::*;
+use crate::sidecar::Sidecar;
+use tondi_addresses::Prefix as AddressPrefix;
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Contact {
+    pub name: String,
+    pub address: String,
+    pub network: Network,
+    #[serde(default)]
+    pub note: String,
+}
+
+impl Contact {
+    pub fn new(name: &str, address: &str, network: Network, note: &str) -> Self {
+        Self {
+            name: name.trim().to_string(),
+            address: address.trim().to_string(),
+            network,
+            note: note.trim().to_string(),
+        }
+    }
+
+    /// Case-insensitive match against a lowercase search term.
+    pub fn matches(&self, search: &str) -> bool {
+        self.name.to_lowercase().contains(search)
+            || self.address.to_lowercase().contains(search)
+            || self.note.to_lowercase().contains(search)
+    }
+
+    /// Returns a warning if the contact address is invalid or does not
+    /// belong to the given network.
+    pub fn warning(&self, network: Network) -> Option<String> {
+        match Address::try_from(self.address.as_str()) {
+            Err(err) => Some(i18n_args("Invalid address: {err}", &[("err", err.to_string())])),
+            Ok(address) if address.prefix != AddressPrefix::from(network) => Some(i18n_args(
+                "The address prefix '{prefix}' does not match the current network ({network})",
+                &[("prefix", address.prefix.to_string()), ("network", network.to_string())],
+            )),
+            Ok(_) if self.network != network => Some(i18n_args(
+                "This contact is saved for {contact_network}, the current network is {network}",
+                &[("contact_network", self.network.to_string()), ("network", network.to_string())],
+            )),
+            Ok(_) => None,
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct AddressBook {
+    #[serde(default)]
+    pub contacts: Vec<Contact>,
+}
+
+impl Sidecar for AddressBook {
+    const EXTENSION: &'static str = "contacts";
+}
+
+impl AddressBook {
+    pub fn find(&self, address: &str) -> Option<&Contact> {
+        let address = address.trim();
+        self.contacts.iter().find(|contact| contact.address == address)
+    }
+
+    /// Insert a contact, replacing an existing contact with the same
+    /// address. Contacts are kept sorted by name.
+    pub fn insert(&mut self, contact: Contact) {
+        self.contacts.retain(|existing| existing.address != contact.address);
+        self.contacts.push(contact);
+        self.contacts.sort_by_key(|contact| contact.name.to_lowercase());
+    }
+
+    pub fn remove(&mut self, address: &str) {
+        self.contacts.retain(|contact| contact.address != address);
+    }
+
+    /// Contacts matching the search text, contacts of the given
+    /// network first.
+    pub fn search(&self, text: &str, network: Network) -> Vec<&Contact> {
+        let search = text.trim().to_lowercase();
+        let mut contacts = self
+            .contacts
+            .iter()
+            .filter(|contact| search.is_empty() || contact.matches(&search))
+            .collect::<Vec<_>>();
+        contacts.sort_by_key(|contact| contact.network != network);
+        contacts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tondi_addresses::Version;
+
+    #[test]
+    fn test_address_book() {
+        let mainnet = Address::new(AddressPrefix::Mainnet, Version::PubKey, &[1u8; 32]).to_string();
+        let testnet = Address::new(AddressPrefix::Testnet, Version::PubKey, &[2u8; 32]).to_string();
+
+        let mut book = AddressBook::default();
+        book.insert(Contact::new(" Bob ", &mainnet, Network::Mainnet, ""));
+        book.insert(Contact::new("alice", &testnet, Network::Mainnet, "exchange"));
+        assert_eq!(book.contacts[0].name, "alice");
+        assert_eq!(book.find(&mainnet).unwrap().name, "Bob");
+
+        assert!(book.find(&mainnet).unwrap().warning(Network::Mainnet).is_none());
+        assert!(book.find(&testnet).unwrap().warning(Network::Mainnet).is_some());
+        assert!(Contact::new("x", "invalid", Network::Mainnet, "").warning(Network::Mainnet).is_some());
+
+        assert_eq!(book.search("EXCH", Network::Mainnet).len(), 1);
+        assert_eq!(book.search("", Network::Mainnet)[0].name, "Bob");
+
+        book.insert(Contact::new("Robert", &mainnet, Network::Mainnet, ""));
+        assert_eq!(book.contacts.len(), 2);
+        book.remove(&testnet);
+        assert_eq!(book.contacts.len(), 1);
+    }
+}
@@ -2,6 +2,8 @@ use crate::frame::window_frame;
 use crate::imports::*;
 use crate::market::*;
 use crate::mobile::MobileMenu;
+use crate::runtime::services::tondi::DaemonShutdown;
+use crate::sidecar::{self, Sidecar, SidecarWriter};
 use egui::load::Bytes;
 use egui_notify::Toasts;
 use tondi_wallet_core::api::TransactionsDataGetResponse;
@@ -48,6 +50,10 @@ pub struct Core {
 
     pub wallet_descriptor: Option<WalletDescriptor>,
     pub wallet_list: Vec<WalletDescriptor>,
+    pub wallet_notes: Option<WalletNotes>,
+    wallet_notes_writer: SidecarWriter<WalletNotes>,
+    pub address_book: Option<AddressBook>,
+    address_book_writer: SidecarWriter<AddressBook>,
     pub prv_key_data_map: Option<HashMap<PrvKeyDataId, Arc<PrvKeyDataInfo>>>,
     pub account_collection: Option<AccountCollection>,
     pub release: Option<Release>,
@@ -63,6 +69,7 @@ pub struct Core {
     // pub feerate : Option<Arc<RpcFeeEstimate>>,
     pub feerate: Option<FeerateEstimate>,
     pub node_info: Option<Box<String>>,
+    pub daemon_shutdown: Option<DaemonShutdown>,
 }
 
 impl Core {
@@ -206,6 +213,10 @@ impl Core {
 
             wallet_descriptor: None,
             wallet_list: Vec::new(),
+            wallet_notes: None,
+            wallet_notes_writer: SidecarWriter::default(),
+            address_book: None,
+            address_book_writer: SidecarWriter::default(),
             prv_key_data_map: None,
             account_collection: None,
             state: Default::default(),
@@ -226,6 +237,7 @@ impl Core {
             storage,
             feerate: None,
             node_info: None,
+            daemon_shutdown: None,
             // daemon_storage_root: Mutex::new(daemon_storage_root),
         };
 
@@ -278,6 +290,17 @@ impl Core {
         }
     }
 
+    /// Fiat conversion of amounts in the user's selected currency
+    /// if enabled and market data for the current network is available
+    pub fn fiat(&self) -> Option<Fiat<'_>> {
+        let is_priced_network = self.settings.node.network == Network::Mainnet || self.settings.developer.market_monitor_on_testnet;
+        if !self.settings.user_interface.fiat || !self.settings.market_monitor || !is_priced_network {
+            return None;
+        }
+
+        self.market.as_ref()?.fiat(&self.settings.user_interface.fiat_currency)
+    }
+
     pub fn balance_padding(&self) -> bool {
         self.settings.user_interface.balance_padding
     }
@@ -392,13 +415,8 @@ impl Core {
         if self.settings.node.network != network {
             log::info!("Changing network from {:?} to {:?}", self.settings.node.network, network);
             
-            // Clear devnet custom URL when switching away from devnet
-            if self.settings.node.network == Network::Devnet && network != Network::Devnet {
-                self.settings.node.devnet_custom_url = None;
-                log::info!("Cleared devnet custom URL when switching away from devnet");
-            }
-            
             self.settings.node.network = network;
+            self.settings.active_node_profile = None;
             
             // 自动更新端口配置以匹配新的网络类型
             self.settings.node.update_ports_for_network();
@@ -415,6 +433,40 @@ impl Core {
             log::info!("Network change completed successfully");
         }
     }
+
+    pub fn apply_node_profile(&mut self, name: &str) {
+        let Some(profile) = self
+            .settings
+            .node_profiles
+            .iter()
+            .find(|profile| profile.name == name)
+            .cloned()
+        else {
+            return;
+        };
+
+        let node_settings = profile.apply(&self.settings.node).and_then(|node_settings| {
+            self.runtime
+                .tondi_service()
+                .try_update_services(&node_settings, None)
+                .map(|_| node_settings)
+        });
+
+        match node_settings {
+            Ok(node_settings) => {
+                self.settings.node = node_settings;
+                self.settings.active_node_profile = Some(profile.name.clone());
+                self.store_settings();
+            }
+            Err(err) => {
+                runtime().error(format!(
+                    "{} `{}`: {err}",
+                    i18n("Unable to apply node profile"),
+                    profile.name
+                ));
+            }
+        }
+    }
 }
 
 impl eframe::App for Core {
@@ -746,6 +798,9 @@ impl Core {
                     MarketUpdate::Ohlc(ohlc) => {
                         self.market.as_mut().unwrap().ohlc.replace(ohlc);
                     }
+                    MarketUpdate::History(history) => {
+                        self.market.as_mut().unwrap().history.replace(history);
+                    }
                 }
             }
             Events::ThemeChange => {
@@ -804,6 +859,31 @@ impl Core {
                 self.wallet_list.clone_from(&*wallet_list);
                 self.wallet_list.sort();
             }
+            Events::WalletNotes {
+                wallet_filename,
+                notes,
+            } => {
+                // discard notes of a wallet that has been closed in the meantime
+                if self
+                    .wallet_descriptor
+                    .as_ref()
+                    .is_some_and(|descriptor| descriptor.filename == wallet_filename)
+                {
+                    self.wallet_notes = Some(*notes);
+                }
+            }
+            Events::AddressBook {
+                wallet_filename,
+                address_book,
+            } => {
+                if self
+                    .wallet_descriptor
+                    .as_ref()
+                    .is_some_and(|descriptor| descriptor.filename == wallet_filename)
+                {
+                    self.address_book = Some(*address_book);
+                }
+            }
             Events::WalletUpdate => {
                 self.select::<modules::AccountManager>();
                 self.get_mut::<modules::WalletOpen>().state = Default::default();
@@ -831,6 +911,9 @@ impl Core {
             Events::NodeInfo { node_info } => {
                 self.node_info = node_info;
             }
+            Events::DaemonShutdown { progress } => {
+                self.daemon_shutdown = progress;
+            }
             Events::Close => {}
             Events::UnlockSuccess => {}
             Events::UnlockFailure { .. } => {}
@@ -955,6 +1038,8 @@ impl Core {
                         self.state.is_open = true;
 
                         self.wallet_descriptor = wallet_descriptor;
+                        self.load_wallet_notes();
+                        self.load_address_book();
                         let network_id = self
                             .state
                             .network_id
@@ -970,6 +1055,8 @@ impl Core {
                         self.state.is_open = true;
 
                         self.wallet_descriptor = wallet_descriptor;
+                        self.load_wallet_notes();
+                        self.load_address_book();
                         let network_id = self
                             .state
                             .network_id
@@ -984,6 +1071,8 @@ impl Core {
                     } => {
                         self.wallet_list.push(wallet_descriptor.clone());
                         self.wallet_descriptor = Some(wallet_descriptor);
+                        self.load_wallet_notes();
+                        self.load_address_book();
                         self.account_collection = Some(AccountCollection::default());
                         self.state.is_open = true;
                     }
@@ -1017,6 +1106,8 @@ impl Core {
                         self.state.is_open = false;
                         self.account_collection = None;
                         self.wallet_descriptor = None;
+                        self.wallet_notes = None;
+                        self.address_book = None;
                         self.prv_key_data_map = None;
 
                         self.modules.clone().into_iter().for_each(|(_, module)| {
@@ -1189,6 +1280,80 @@ impl Core {
         });
     }
 
+    /// Load a sidecar file of the currently open wallet and deliver it
+    /// through the event produced by `event`. If the file can not be read,
+    /// the error is reported and the data is never delivered, leaving it
+    /// unavailable so that the file is not overwritten.
+    fn load_sidecar<T: Sidecar>(&self, event: fn(String, Box<T>) -> Events, error_caption: &str) {
+        if let Some(wallet_filename) = self
+            .wallet_descriptor
+            .as_ref()
+            .map(|descriptor| descriptor.filename.clone())
+        {
+            let runtime = self.runtime.clone();
+            let error_caption = error_caption.to_string();
+            spawn(async move {
+                match sidecar::load::<T>(&wallet_filename).await {
+                    Ok(data) => {
+                        runtime.send(event(wallet_filename, Box::new(data))).await?;
+                    }
+                    Err(err) => {
+                        runtime.error(format!("{error_caption}: {err}"));
+                    }
+                }
+                Ok(())
+            });
+        }
+    }
+
+    fn load_wallet_notes(&mut self) {
+        self.wallet_notes = None;
+        self.load_sidecar::<WalletNotes>(
+            |wallet_filename, notes| Events::WalletNotes {
+                wallet_filename,
+                notes,
+            },
+            i18n("Unable to load notes"),
+        );
+    }
+
+    /// Persist [`Core::wallet_notes`] next to the currently open wallet.
+    pub fn store_wallet_notes(&self) {
+        if let (Some(descriptor), Some(notes)) =
+            (self.wallet_descriptor.as_ref(), self.wallet_notes.clone())
+        {
+            self.wallet_notes_writer.store(
+                descriptor.filename.clone(),
+                notes,
+                i18n("Unable to store notes").to_string(),
+            );
+        }
+    }
+
+    fn load_address_book(&mut self) {
+        self.address_book = None;
+        self.load_sidecar::<AddressBook>(
+            |wallet_filename, address_book| Events::AddressBook {
+                wallet_filename,
+                address_book,
+            },
+            i18n("Unable to load contacts"),
+        );
+    }
+
+    /// Persist [`Core::address_book`] next to the currently open wallet.
+    pub fn store_address_book(&self) {
+        if let (Some(descriptor), Some(address_book)) =
+            (self.wallet_descriptor.as_ref(), self.address_book.clone())
+        {
+            self.address_book_writer.store(
+                descriptor.filename.clone(),
+                address_book,
+                i18n("Unable to store contacts").to_string(),
+            );
+        }
+    }
+
     pub fn load_account_transactions_with_range(
         &mut self,
         account: &Account,
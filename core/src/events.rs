@@ -1,5 +1,6 @@
 use crate::imports::*;
 use crate::market::*;
+use crate::runtime::services::tondi::DaemonShutdown;
 use crate::storage::StorageUpdateOptions;
 use crate::utils::Release;
 use tondi_metrics_core::MetricsSnapshot;
@@ -31,6 +32,14 @@ pub enum Events {
     WalletList {
         wallet_list: Arc<Vec<WalletDescriptor>>,
     },
+    WalletNotes {
+        wallet_filename: String,
+        notes: Box<WalletNotes>,
+    },
+    AddressBook {
+        wallet_filename: String,
+        address_book: Box<AddressBook>,
+    },
     Wallet {
         event: Box<tondi::Events>,
     },
@@ -48,6 +57,9 @@ pub enum Events {
     NodeInfo {
         node_info: Option<Box<String>>,
     },
+    DaemonShutdown {
+        progress: Option<DaemonShutdown>,
+    },
     Close,
     Exit,
 }
@@ -79,11 +79,13 @@ pub use crate::events::{ApplicationEventsChannel, Events};
 pub use crate::extensions::*;
 pub use crate::interop;
 pub use crate::market::MarketData;
+pub use crate::contacts::{AddressBook, Contact};
 pub use crate::menu::Menu;
 pub use crate::modules;
 pub use crate::modules::{Module, ModuleCaps, ModuleStyle, ModuleT};
 pub use crate::network::BASIC_TRANSACTION_MASS;
 pub use crate::network::NetworkPressure;
+pub use crate::notes::{AccountNotes, Note, WalletNotes};
 pub use crate::notifications::{Notifications, UserNotification, UserNotifyKind};
 pub use crate::primitives::{
     Account, AccountCollection, AccountSelectorButtonExtension, BlockDagGraphSettings, DaaBucket,
@@ -92,8 +94,8 @@ pub use crate::primitives::{
 pub use crate::result::Result;
 pub use crate::runtime::{runtime, spawn, spawn_with_result, Payload, Runtime, Service};
 pub use crate::settings::{
-    EstimatorMode, EstimatorSettings, TondidNodeKind,
-    NodeConnectionConfigKind, NodeMemoryScale, NodeSettings, RpcOptions, Settings,
+    network_defaults, EstimatorMode, EstimatorSettings, MarketDataProviderKind, MarketMonitorSettings,
+    MetricAlertCondition, MetricAlertRule, TondidNodeKind, NodeConnectionConfigKind, NodeMemoryScale, NodeProfile, NodeSettings, PrometheusSettings, RpcOptions, Settings,
     UserInterfaceSettings,
 };
 pub use crate::state::State;
@@ -7,6 +7,7 @@ pub use core::Core;
 
 pub mod app;
 pub mod collection;
+pub mod contacts;
 pub mod device;
 pub mod egui;
 pub mod error;
@@ -21,11 +22,14 @@ pub mod menu;
 pub mod mobile;
 pub mod modules;
 pub mod network;
+pub mod notes;
 pub mod notifications;
 pub mod primitives;
+pub mod probe;
 pub mod result;
 pub mod runtime;
 pub mod settings;
+pub mod sidecar;
 pub mod state;
 pub mod status;
 pub mod storage;
@@ -24,19 +24,198 @@ impl MarketData {
 
 pub type MarketDataMap = AHashMap<String, MarketData>;
 
+/// Time range of the OHLC price history
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum OhlcRange {
+    #[default]
+    Day,
+    Week,
+    Month,
+    Quarter,
+    Year,
+}
+
+impl OhlcRange {
+    pub fn iter() -> impl Iterator<Item = &'static OhlcRange> {
+        [
+            OhlcRange::Day,
+            OhlcRange::Week,
+            OhlcRange::Month,
+            OhlcRange::Quarter,
+            OhlcRange::Year,
+        ]
+        .iter()
+    }
+
+    pub fn days(&self) -> u64 {
+        match self {
+            OhlcRange::Day => 1,
+            OhlcRange::Week => 7,
+            OhlcRange::Month => 30,
+            OhlcRange::Quarter => 90,
+            OhlcRange::Year => 365,
+        }
+    }
+
+    /// Time after which cached candles of this range are refreshed;
+    /// candle granularity grows with the range, so longer ranges
+    /// change less often.
+    pub fn cache_duration(&self) -> Duration {
+        match self {
+            OhlcRange::Day => Duration::from_secs(5 * 60),
+            OhlcRange::Week | OhlcRange::Month => Duration::from_secs(30 * 60),
+            OhlcRange::Quarter | OhlcRange::Year => Duration::from_secs(4 * 60 * 60),
+        }
+    }
+}
+
+impl std::fmt::Display for OhlcRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OhlcRange::Day => write!(f, "24H"),
+            OhlcRange::Week => write!(f, "7D"),
+            OhlcRange::Month => write!(f, "30D"),
+            OhlcRange::Quarter => write!(f, "90D"),
+            OhlcRange::Year => write!(f, "1Y"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct OhlcCandle {
+    /// Unix time in milliseconds of the candle close
+    pub timestamp: f64,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+}
+
 #[derive(Default, Debug)]
-pub struct Ohlc {}
+pub struct Ohlc {
+    pub range: OhlcRange,
+    pub candles: Vec<OhlcCandle>,
+    pub precision: usize,
+}
+
+impl Ohlc {
+    pub fn new(symbol: &str, range: OhlcRange, candles: Vec<OhlcCandle>) -> Self {
+        Self {
+            range,
+            candles,
+            precision: precision_from_symbol(symbol),
+        }
+    }
+}
 
 pub type OhlcMap = AHashMap<String, Ohlc>;
 
+impl Ohlc {
+    /// Close price of the candle covering `timestamp` (unix time in
+    /// milliseconds) or `None` if it falls outside of the candle range
+    pub fn price_at(&self, timestamp: f64) -> Option<f64> {
+        let first = self.candles.first()?;
+        let last = self.candles.last()?;
+        let spacing = self.candles.get(1).map(|next| next.timestamp - first.timestamp).unwrap_or_default();
+        if timestamp < first.timestamp - spacing || timestamp > last.timestamp {
+            return None;
+        }
+
+        let index = self.candles.partition_point(|candle| candle.timestamp < timestamp);
+        self.candles.get(index).map(|candle| candle.close)
+    }
+}
+
 #[derive(Default, Debug)]
 pub struct Market {
     pub price: Option<Arc<MarketDataMap>>,
     pub ohlc: Option<Arc<OhlcMap>>,
+    /// Long range price history used to value past transactions
+    pub history: Option<Arc<OhlcMap>>,
+}
+
+impl Market {
+    pub fn fiat(&self, symbol: &str) -> Option<Fiat<'_>> {
+        let symbol = symbol.to_lowercase();
+        let data = self.price.as_ref()?.get(&symbol)?;
+        let history = self.history.as_ref().and_then(|history| history.get(&symbol));
+        Some(Fiat {
+            symbol: symbol.to_uppercase(),
+            price: data.price,
+            precision: data.precision,
+            history,
+        })
+    }
+}
+
+/// Converts TONDI amounts to a fiat currency at the current
+/// price or, where available, at the price of a past time.
+#[derive(Debug, Clone)]
+pub struct Fiat<'market> {
+    pub symbol: String,
+    pub price: f64,
+    pub precision: usize,
+    history: Option<&'market Ohlc>,
+}
+
+impl Fiat<'_> {
+    /// Price at `timestamp` (unix time in milliseconds), falling back
+    /// to the current price if no historical price is available
+    pub fn price_at(&self, timestamp: Option<u64>) -> f64 {
+        timestamp
+            .zip(self.history)
+            .and_then(|(timestamp, history)| history.price_at(timestamp as f64))
+            .unwrap_or(self.price)
+    }
+
+    pub fn format(&self, sompi: u64) -> String {
+        self.format_at(sompi, None)
+    }
+
+    pub fn format_at(&self, sompi: u64, timestamp: Option<u64>) -> String {
+        let value = sompi_to_tondi(sompi) * self.price_at(timestamp);
+        format!("~{}", format_currency_with_symbol(value, self.precision, self.symbol.as_str()))
+    }
 }
 
 #[derive(Clone, Debug)]
 pub enum MarketUpdate {
     Price(Arc<MarketDataMap>),
     Ohlc(Arc<OhlcMap>),
+    History(Arc<OhlcMap>),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fiat_historical_price() {
+        let candles = (1..=4)
+            .map(|n| OhlcCandle {
+                timestamp: n as f64 * 1000.0,
+                open: n as f64,
+                high: n as f64,
+                low: n as f64,
+                close: n as f64,
+            })
+            .collect();
+        let history = Ohlc::new("usd", OhlcRange::Year, candles);
+
+        assert_eq!(history.price_at(500.0), Some(1.0));
+        assert_eq!(history.price_at(2500.0), Some(3.0));
+        assert_eq!(history.price_at(4000.0), Some(4.0));
+        assert_eq!(history.price_at(-500.0), None);
+        assert_eq!(history.price_at(4500.0), None);
+
+        let fiat = Fiat {
+            symbol: "USD".to_string(),
+            price: 10.0,
+            precision: 2,
+            history: Some(&history),
+        };
+        assert_eq!(fiat.price_at(Some(2000)), 2.0);
+        assert_eq!(fiat.price_at(Some(9000)), 10.0);
+        assert_eq!(fiat.price_at(None), 10.0);
+    }
 }
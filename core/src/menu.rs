@@ -393,6 +393,18 @@ impl<'core> Menu<'core> {
                 self.select::<modules::BlockDag>();
                 ui.close_menu();
             }
+
+            ui.separator();
+            if ui.button(i18n("Block Explorer")).clicked() {
+                self.select::<modules::Explorer>();
+                ui.close_menu();
+            }
+
+            ui.separator();
+            if ui.button(i18n("Mempool")).clicked() {
+                self.select::<modules::Mempool>();
+                ui.close_menu();
+            }
         }
 
         #[cfg(not(target_arch = "wasm32"))]
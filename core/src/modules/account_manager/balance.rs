@@ -31,7 +31,17 @@ impl<'context> BalancePane<'context> {
                 );
             }
 
-            if core.settings.market_monitor && (core.settings.node.network == Network::Mainnet || core.settings.developer.market_monitor_on_testnet) {
+            let fiat = core.fiat();
+            let with_fiat = |text: String, sompi: u64| match fiat.as_ref() {
+                Some(fiat) => format!("{text}  {}", fiat.format(sompi)),
+                None => text,
+            };
+
+            if let Some(fiat) = fiat.as_ref() {
+                ui.vertical_centered(|ui| {
+                    ui.label(RichText::new(fiat.format(balance.mature)).font(FontId::proportional(14.)));
+                });
+            } else if core.settings.market_monitor && (core.settings.node.network == Network::Mainnet || core.settings.developer.market_monitor_on_testnet) {
                 if let Some(market) = core.market.as_ref() {
                     if let Some(price_list) = market.price.as_ref() {
                         let mut symbols = price_list.keys().collect::<Vec<_>>();
@@ -53,22 +63,22 @@ impl<'context> BalancePane<'context> {
             }
             
             if balance.pending != 0 {
-                ui.label(i18n_args(
+                ui.label(with_fiat(i18n_args(
                     "Pending: {amount}",
                     &[("amount", &sompi_to_tondi_string_with_suffix(
                         balance.pending,
                         network_type
                     ))]
-                ));
+                ), balance.pending));
             }
             if balance.outgoing != 0 {
-                ui.label(i18n_args(
+                ui.label(with_fiat(i18n_args(
                     "Sending: {amount}",
                     &[("amount", &sompi_to_tondi_string_with_suffix(
                         balance.outgoing,
                         network_type
                     ))]
-                ));
+                ), balance.outgoing));
             }
 
             ui.add_space(10.);
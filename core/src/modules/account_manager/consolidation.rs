@@ -0,0 +1,400 @@
+use crate::imports::*;
+use super::*;
+use egui_phosphor::light::{CHECK, X};
+use tondi_wallet_core::tx::{PaymentDestination, PaymentOutputs};
+use tondi_wallet_core::utxo::NetworkParams;
+
+pub const MAX_SPLIT_OUTPUTS: usize = 100;
+const DEFAULT_DUST_THRESHOLD: &str = "1";
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ConsolidationKind {
+    /// Sweep mature UTXOs below the dust threshold into a single change output.
+    Dust,
+    /// Break a single UTXO into N equal outputs.
+    Split(Outpoint),
+}
+
+#[derive(Default, Clone)]
+enum ConsolidationState {
+    #[default]
+    Closed,
+    Settings,
+    Estimating,
+    Ready(ConsolidationEstimate),
+    Processing,
+    Complete(GeneratorSummary),
+    Error(String),
+}
+
+/// Estimate of the exact transaction that will be submitted.
+#[derive(Clone)]
+struct ConsolidationEstimate {
+    summary: GeneratorSummary,
+    destination: PaymentDestination,
+    priority_fee_sompi: u64,
+    /// Values of the split outputs; the consolidated dust output is the change output.
+    outputs: Vec<u64>,
+}
+
+/// Mature UTXOs with a value below the `threshold`.
+pub fn dust_utxos<'utxos>(utxos: &'utxos [UtxoRecord], threshold: u64, params: &NetworkParams, current_daa_score: u64) -> Vec<&'utxos UtxoRecord> {
+    utxos.iter()
+        .filter(|utxo| utxo.amount < threshold && utxo.maturity_progress(params, current_daa_score).is_none())
+        .collect()
+}
+
+/// Split `amount` minus `fees` into `parts` equal outputs. The remainder of
+/// the division, as well as any difference between `fees` and the actual
+/// transaction fees, is returned to the change address by the generator
+/// (or absorbed into the fees when it is too small for a change output).
+pub fn split_amounts(amount: u64, parts: usize, fees: u64) -> Option<Vec<u64>> {
+    if parts == 0 {
+        return None;
+    }
+
+    let output = amount.checked_sub(fees)? / parts as u64;
+    (output > 0).then(|| vec![output; parts])
+}
+
+pub struct Consolidation {
+    kind: ConsolidationKind,
+    state: ConsolidationState,
+    dust_threshold_text: String,
+    split_count: usize,
+    fee_mode: FeeMode,
+    wallet_secret: String,
+    payment_secret: String,
+}
+
+impl Default for Consolidation {
+    fn default() -> Self {
+        Self {
+            kind: ConsolidationKind::Dust,
+            state: ConsolidationState::Closed,
+            dust_threshold_text: DEFAULT_DUST_THRESHOLD.to_string(),
+            split_count: 2,
+            fee_mode: FeeMode::Low(FeerateBucket::default()),
+            wallet_secret: String::default(),
+            payment_secret: String::default(),
+        }
+    }
+}
+
+impl Zeroize for Consolidation {
+    fn zeroize(&mut self) {
+        self.wallet_secret.zeroize();
+        self.payment_secret.zeroize();
+    }
+}
+
+impl Consolidation {
+    pub fn open(&mut self, kind: ConsolidationKind) {
+        self.kind = kind;
+        self.state = ConsolidationState::Settings;
+        self.zeroize();
+    }
+
+    pub fn close(&mut self) {
+        self.state = ConsolidationState::Closed;
+        self.zeroize();
+    }
+
+    pub fn is_open(&self) -> bool {
+        !matches!(self.state, ConsolidationState::Closed)
+    }
+
+    fn fee_modes(core: &Core) -> Vec<FeeMode> {
+        if let Some(fees) = core.feerate.as_ref() {
+            vec![
+                FeeMode::Low(fees.low.value()),
+                FeeMode::Economic(fees.economic.value()),
+                FeeMode::Priority(fees.priority.value()),
+            ]
+        } else {
+            vec![FeeMode::Low(FeerateBucket::new(1.0, 5.0))]
+        }
+    }
+
+    /// UTXOs funding the operation and their total value.
+    fn inputs(&self, core: &Core, utxos: &[UtxoRecord], rc: &RenderContext) -> (Vec<Outpoint>, u64) {
+        let inputs = match self.kind {
+            ConsolidationKind::Dust => {
+                let threshold = try_tondi_str_to_sompi(self.dust_threshold_text.as_str()).ok().flatten().unwrap_or_default();
+                let params: &'static NetworkParams = core.network().into();
+                rc.current_daa_score
+                    .map(|current_daa_score| dust_utxos(utxos, threshold, params, current_daa_score))
+                    .unwrap_or_default()
+            }
+            ConsolidationKind::Split(outpoint) => {
+                utxos.iter().filter(|utxo| utxo.outpoint == outpoint).collect()
+            }
+        };
+
+        (inputs.iter().map(|utxo| utxo.outpoint).collect(), inputs.iter().map(|utxo| utxo.amount).sum())
+    }
+
+    /// Output values for the operation and the matching payment destination.
+    fn destination(kind: ConsolidationKind, address: &Address, amount: u64, split_count: usize, fees: u64) -> Option<(Vec<u64>, PaymentDestination)> {
+        match kind {
+            ConsolidationKind::Dust => Some((vec![amount.checked_sub(fees)?], PaymentDestination::Change)),
+            ConsolidationKind::Split(_) => {
+                let amounts = split_amounts(amount, split_count, fees)?;
+                let outputs = amounts.iter()
+                    .map(|amount| PaymentOutput { address: address.clone(), amount: *amount })
+                    .collect();
+                Some((amounts, PaymentDestination::PaymentOutputs(PaymentOutputs { outputs })))
+            }
+        }
+    }
+
+    /// Estimate the mass of the operation, derive the fees for the selected
+    /// fee rate and estimate the exact transaction that will be submitted.
+    async fn estimate(
+        account_id: AccountId,
+        kind: ConsolidationKind,
+        address: Address,
+        outpoints: Vec<Outpoint>,
+        amount: u64,
+        split_count: usize,
+        feerate: f64,
+    ) -> Result<ConsolidationEstimate> {
+        let insufficient_funds = || Error::custom(i18n("Insufficient funds to cover the fees"));
+
+        // split outputs are estimated at half value, overestimating the storage mass
+        let (_, destination) = Self::destination(kind, &address, amount, split_count, amount / 2).ok_or_else(insufficient_funds)?;
+        let mass_estimate = estimate_with_utxos(account_id, outpoints.clone(), destination, Fees::SenderPays(0)).await?;
+
+        // the mass estimate is produced with the minimum fee rate, making aggregated fees equivalent to the mass
+        let priority_fee_sompi = (feerate * mass_estimate.aggregated_fees as f64) as u64;
+        let fees = mass_estimate.aggregated_fees + priority_fee_sompi;
+
+        let (outputs, destination) = Self::destination(kind, &address, amount, split_count, fees).ok_or_else(insufficient_funds)?;
+        let summary = estimate_with_utxos(account_id, outpoints, destination.clone(), Fees::SenderPays(priority_fee_sompi)).await?;
+
+        Ok(ConsolidationEstimate { summary, destination, priority_fee_sompi, outputs })
+    }
+
+    /// Returns `true` once transactions have been submitted and the UTXO list should be reloaded.
+    pub fn render(&mut self, core: &mut Core, ui: &mut Ui, rc: &RenderContext, utxos: &[UtxoRecord]) -> bool {
+        let RenderContext { account, network_type, .. } = rc;
+
+        let estimate_result = Payload::<Result<ConsolidationEstimate>>::new("utxo_consolidation_estimate");
+        let send_result = Payload::<Result<GeneratorSummary>>::new("utxo_consolidation_result");
+
+        let fee_modes = Self::fee_modes(core);
+        let fee_mode = fee_modes.iter().find(|mode| **mode == self.fee_mode).cloned().unwrap_or(fee_modes[0]);
+        let (outpoints, amount) = self.inputs(core, utxos, rc);
+        let network_below_capacity = core.network_pressure.below_capacity();
+
+        let mut reload = false;
+
+        ui.add_space(8.);
+        match self.kind {
+            ConsolidationKind::Dust => {
+                ui.label(RichText::new(i18n("Consolidate Dust")).strong());
+            }
+            ConsolidationKind::Split(_) => {
+                ui.label(RichText::new(i18n("Split UTXO")).strong());
+            }
+        }
+
+        match self.state.clone() {
+            ConsolidationState::Closed => { }
+            ConsolidationState::Settings => {
+                match self.kind {
+                    ConsolidationKind::Dust => {
+                        ui.horizontal(|ui| {
+                            ui.label(i18n("Dust threshold:"));
+                            ui.add(TextEdit::singleline(&mut self.dust_threshold_text).desired_width(80.));
+                            ui.label(tondi_suffix(network_type));
+                        });
+                        ui.label(i18n_args("{count} UTXOs totaling {amount} will be consolidated", &[
+                            ("count", outpoints.len().separated_string()),
+                            ("amount", sompi_to_tondi_string_with_suffix(amount, network_type)),
+                        ]));
+                    }
+                    ConsolidationKind::Split(_) => {
+                        ui.horizontal(|ui| {
+                            ui.label(i18n("Number of outputs:"));
+                            ui.add(DragValue::new(&mut self.split_count).range(2..=MAX_SPLIT_OUTPUTS));
+                        });
+                        ui.label(i18n_args("Splitting {amount} into {count} outputs", &[
+                            ("amount", sompi_to_tondi_string_with_suffix(amount, network_type)),
+                            ("count", self.split_count.to_string()),
+                        ]));
+                    }
+                }
+
+                ui.horizontal(|ui| {
+                    ui.label(i18n("Fee tier:"));
+                    for mode in fee_modes.iter() {
+                        if ui.radio(*mode == fee_mode, i18n(mode.to_string().as_str()))
+                            .on_hover_text(format!("{} SOMPI/g", format_with_precision(mode.bucket().feerate)))
+                            .clicked() {
+                            self.fee_mode = *mode;
+                        }
+                    }
+                });
+
+                ui.label(i18n_args("Network Pressure: ~{number}%", &[("number", core.network_pressure.capacity().to_string())]));
+
+                let kind_is_ready = match self.kind {
+                    ConsolidationKind::Dust if !network_below_capacity => {
+                        ui.label(RichText::new(i18n("Consolidation is available only while the network is below capacity.")).color(theme_color().warning_color));
+                        false
+                    }
+                    ConsolidationKind::Dust => outpoints.len() > 1,
+                    ConsolidationKind::Split(_) => outpoints.len() == 1 && split_amounts(amount, self.split_count, 0).is_some(),
+                };
+
+                ui.add_space(8.);
+                ui.horizontal(|ui| {
+                    if ui.add_enabled(kind_is_ready, Button::new(i18n("Estimate"))).clicked() {
+                        let account_id = account.id();
+                        let kind = self.kind;
+                        let address = rc.context.address().clone();
+                        let outpoints = outpoints.clone();
+                        let split_count = self.split_count;
+                        let feerate = fee_mode.bucket().feerate;
+                        spawn_with_result(&estimate_result, async move {
+                            let estimate = Self::estimate(account_id, kind, address, outpoints, amount, split_count, feerate).await?;
+                            runtime().request_repaint();
+                            Ok(estimate)
+                        });
+                        self.state = ConsolidationState::Estimating;
+                    }
+
+                    if ui.button(format!("{X} {}", i18n("Cancel"))).clicked() {
+                        self.close();
+                    }
+                });
+            }
+            ConsolidationState::Estimating => {
+                ui.add(egui::Spinner::new().size(32.));
+
+                if let Some(result) = estimate_result.take() {
+                    match result {
+                        Ok(estimate) => {
+                            self.state = ConsolidationState::Ready(estimate);
+                        }
+                        Err(err) => {
+                            self.state = ConsolidationState::Error(err.to_string());
+                        }
+                    }
+                }
+            }
+            ConsolidationState::Ready(estimate) => {
+                let ConsolidationEstimate { summary, outputs, .. } = &estimate;
+
+                ui.label(i18n_args("Transactions: {number}", &[("number", summary.number_of_generated_transactions.to_string())]));
+                ui.label(i18n_args("UTXOs: {number}", &[("number", summary.aggregated_utxos.to_string())]));
+                ui.label(i18n_args("Fees: {amount}", &[("amount", sompi_to_tondi_string_with_suffix(summary.aggregated_fees, network_type))]));
+
+                match self.kind {
+                    ConsolidationKind::Dust => {
+                        // the consolidated output is the change output, receiving everything left after the fees
+                        ui.label(i18n_args("Outputs: {count} x {amount}", &[
+                            ("count", "1".to_string()),
+                            ("amount", sompi_to_tondi_string_with_suffix(amount.saturating_sub(summary.aggregated_fees), network_type)),
+                        ]));
+                    }
+                    ConsolidationKind::Split(_) => {
+                        ui.label(i18n_args("Outputs: {count} x {amount}", &[
+                            ("count", outputs.len().to_string()),
+                            ("amount", sompi_to_tondi_string_with_suffix(outputs[0], network_type)),
+                        ]));
+
+                        // the division remainder and the fee overestimate are returned as change
+                        let change = amount.saturating_sub(outputs.iter().sum::<u64>() + summary.aggregated_fees);
+                        if change > 0 {
+                            ui.label(i18n_args("Change output: {amount}", &[("amount", sompi_to_tondi_string_with_suffix(change, network_type))]));
+                        }
+                    }
+                }
+
+                ui.add_space(8.);
+                ui.label(RichText::new(i18n("Enter wallet password")).size(12.).raised());
+                ui.add(TextEdit::singleline(&mut self.wallet_secret).password(true).desired_width(f32::INFINITY));
+
+                let requires_payment_passphrase = account.requires_bip39_passphrase(core);
+                if requires_payment_passphrase {
+                    ui.label(RichText::new(i18n("Enter bip39 passphrase")).size(12.).raised());
+                    ui.add(TextEdit::singleline(&mut self.payment_secret).password(true).desired_width(f32::INFINITY));
+                }
+
+                let is_ready_to_send = !self.wallet_secret.is_empty()
+                    && !(requires_payment_passphrase && self.payment_secret.is_empty());
+
+                ui.add_space(8.);
+                ui.horizontal(|ui| {
+                    if ui.add_enabled(is_ready_to_send, Button::new(format!("{CHECK} {}", i18n("Submit")))).clicked() {
+                        let ConsolidationEstimate { destination, priority_fee_sompi, .. } = estimate.clone();
+                        let account_id = account.id();
+                        let wallet_secret = Secret::from(self.wallet_secret.clone());
+                        let payment_secret = requires_payment_passphrase.then_some(Secret::from(self.payment_secret.clone()));
+                        self.zeroize();
+
+                        spawn_with_result(&send_result, async move {
+                            let generator_summary = send_with_utxos(account_id, outpoints, destination, Fees::SenderPays(priority_fee_sompi), wallet_secret, payment_secret).await?;
+                            runtime().request_repaint();
+                            Ok(generator_summary)
+                        });
+
+                        self.state = ConsolidationState::Processing;
+                    }
+
+                    if ui.button(format!("{X} {}", i18n("Cancel"))).clicked() {
+                        self.zeroize();
+                        self.state = ConsolidationState::Settings;
+                    }
+                });
+            }
+            ConsolidationState::Processing => {
+                ui.add(egui::Spinner::new().size(32.));
+
+                if let Some(result) = send_result.take() {
+                    match result {
+                        Ok(generator_summary) => {
+                            self.state = ConsolidationState::Complete(generator_summary);
+                        }
+                        Err(err) => {
+                            self.state = ConsolidationState::Error(err.to_string());
+                        }
+                    }
+                    reload = true;
+                }
+            }
+            ConsolidationState::Complete(generator_summary) => {
+                ui.label(i18n_args("Transaction submitted: {utxos} UTXOs, fees {fees}", &[
+                    ("utxos", generator_summary.aggregated_utxos.to_string()),
+                    ("fees", sompi_to_tondi_string_with_suffix(generator_summary.aggregated_fees, network_type)),
+                ]));
+                if ui.button(i18n("Close")).clicked() {
+                    self.close();
+                }
+            }
+            ConsolidationState::Error(err) => {
+                ui.label(RichText::new(err).color(theme_color().error_color));
+                if ui.button(i18n("Close")).clicked() {
+                    self.close();
+                }
+            }
+        }
+
+        reload
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_amounts() {
+        assert_eq!(split_amounts(1_000, 3, 100), Some(vec![300, 300, 300]));
+        assert_eq!(split_amounts(1_000, 4, 0), Some(vec![250; 4]));
+        assert_eq!(split_amounts(100, 2, 200), None);
+        assert_eq!(split_amounts(100, 0, 0), None);
+    }
+}
@@ -0,0 +1,221 @@
+use crate::imports::*;
+use super::*;
+use egui_phosphor::light::{PENCIL_SIMPLE, TRASH, USER_PLUS, WARNING};
+
+/// Address book contact editor state.
+#[derive(Clone)]
+pub struct ContactEditor {
+    /// Transaction the editor was opened from in the history.
+    pub transaction_id: Option<TransactionId>,
+    /// Address of the contact being edited.
+    pub original: Option<String>,
+    pub name: String,
+    pub address: String,
+    pub network: Network,
+    pub note: String,
+}
+
+impl ContactEditor {
+    pub fn new(network: Network) -> Self {
+        Self {
+            transaction_id: None,
+            original: None,
+            name: String::default(),
+            address: String::default(),
+            network,
+            note: String::default(),
+        }
+    }
+
+    pub fn edit(contact: &Contact) -> Self {
+        Self {
+            transaction_id: None,
+            original: Some(contact.address.clone()),
+            name: contact.name.clone(),
+            address: contact.address.clone(),
+            network: contact.network,
+            note: contact.note.clone(),
+        }
+    }
+
+    pub fn with_transaction(transaction_id: TransactionId, address: &Address, network: Network) -> Self {
+        Self {
+            transaction_id: Some(transaction_id),
+            address: address.to_string(),
+            ..Self::new(network)
+        }
+    }
+
+    pub fn contact(&self) -> Contact {
+        Contact::new(&self.name, &self.address, self.network, &self.note)
+    }
+
+    /// Render the editor form. `addresses` restricts the address to a set
+    /// of candidates (e.g. the addresses of a transaction). Returns the
+    /// contact once the user saves it; `closed` is set on save or cancel.
+    pub fn render(&mut self, ui: &mut Ui, network: Network, addresses: &[Address], closed: &mut bool) -> Option<Contact> {
+        let id = self.transaction_id.map(|id| id.to_string()).unwrap_or_default();
+
+        ui.add_space(4.);
+        egui::Grid::new(format!("contact_editor_{id}"))
+            .num_columns(2)
+            .spacing([8.,4.])
+            .show(ui, |ui| {
+                ui.label(i18n("Name:"));
+                ui.add(TextEdit::singleline(&mut self.name).desired_width(f32::INFINITY));
+                ui.end_row();
+
+                ui.label(i18n("Address:"));
+                if addresses.is_empty() {
+                    ui.add(TextEdit::singleline(&mut self.address).desired_width(f32::INFINITY));
+                } else {
+                    ComboBox::from_id_salt(format!("contact_editor_address_{id}"))
+                        .selected_text(format_address_string(&self.address, Some(8)))
+                        .show_ui(ui, |ui| {
+                            for address in addresses.iter() {
+                                ui.selectable_value(&mut self.address, address.to_string(), format_address(address, Some(8)));
+                            }
+                        });
+                }
+                ui.end_row();
+
+                ui.label(i18n("Network:"));
+                ComboBox::from_id_salt(format!("contact_editor_network_{id}"))
+                    .selected_text(self.network.name())
+                    .show_ui(ui, |ui| {
+                        for network in Network::iter() {
+                            ui.selectable_value(&mut self.network, *network, network.name());
+                        }
+                    });
+                ui.end_row();
+
+                ui.label(i18n("Note:"));
+                ui.add(TextEdit::singleline(&mut self.note).desired_width(f32::INFINITY));
+                ui.end_row();
+            });
+
+        let contact = self.contact();
+        if let Some(warning) = contact.warning(network) {
+            ui.colored_label(theme_color().warning_color, warning);
+        }
+
+        let mut saved = None;
+        ui.horizontal(|ui| {
+            let valid = !contact.name.is_empty() && Address::try_from(contact.address.as_str()).is_ok();
+            if ui.add_enabled(valid, Button::new(i18n("Save")).min_size(theme_style().medium_button_size())).clicked() {
+                saved = Some(contact);
+                *closed = true;
+            }
+            if ui.medium_button(i18n("Cancel")).clicked() {
+                *closed = true;
+            }
+        });
+        ui.add_space(4.);
+
+        saved
+    }
+}
+
+pub struct Contacts<'context> {
+    context : &'context mut ManagerContext,
+}
+
+impl<'context> Contacts<'context> {
+    pub fn new(context : &'context mut ManagerContext) -> Self {
+        Self { context }
+    }
+
+    pub fn render(&mut self, core: &mut Core, ui : &mut Ui, _rc : &RenderContext) {
+        let network = core.network();
+
+        // unavailable while loading or if the contacts file could not be read
+        let Some(address_book) = core.address_book.as_ref() else {
+            ui.vertical_centered(|ui| {
+                ui.label("");
+                ui.label(RichText::new(i18n("Contacts are not available")).size(16.));
+            });
+            return;
+        };
+
+        ui.horizontal(|ui| {
+            ui.add(TextEdit::singleline(&mut self.context.contact_search).hint_text(i18n("Search contacts")).desired_width(200.));
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui.button(format!("{USER_PLUS} {}", i18n("Add contact"))).clicked() {
+                    self.context.contact_editor = Some(ContactEditor::new(network));
+                }
+            });
+        });
+
+        let mut saved = None;
+        if let Some(editor) = self.context.contact_editor.as_mut().filter(|editor| editor.transaction_id.is_none()) {
+            let mut closed = false;
+            saved = editor.render(ui, network, &[], &mut closed).map(|contact| (editor.original.clone(), contact));
+            if closed {
+                self.context.contact_editor = None;
+            }
+        }
+
+        ui.separator();
+
+        let mut edit = None;
+        let mut remove = None;
+        let contacts = address_book.search(&self.context.contact_search, network);
+        egui::ScrollArea::vertical().auto_shrink([false,false]).show(ui, |ui| {
+            if contacts.is_empty() {
+                ui.vertical_centered(|ui| {
+                    ui.label("");
+                    ui.label(RichText::new(i18n("No contacts")).size(16.));
+                });
+                return;
+            }
+
+            egui::Grid::new("address_book")
+                .num_columns(5)
+                .striped(true)
+                .spacing([12.,4.])
+                .show(ui, |ui| {
+                    for contact in contacts.iter() {
+                        ui.label(RichText::new(&contact.name).color(theme_color().strong_color));
+                        ui.label(format_address_string(&contact.address, Some(8))).on_hover_text(&contact.address);
+                        ui.label(contact.network.name());
+                        ui.label(&contact.note);
+                        ui.horizontal(|ui| {
+                            if let Some(warning) = contact.warning(network) {
+                                ui.label(RichText::new(WARNING).color(theme_color().warning_color)).on_hover_text(warning);
+                            }
+                            if ui.add(Label::new(RichText::new(PENCIL_SIMPLE).size(14.)).sense(Sense::click())).on_hover_text(i18n("Edit")).clicked() {
+                                edit = Some(ContactEditor::edit(contact));
+                            }
+                            if ui.add(Label::new(RichText::new(TRASH).size(14.)).sense(Sense::click())).on_hover_text(i18n("Remove")).clicked() {
+                                remove = Some(contact.address.clone());
+                            }
+                        });
+                        ui.end_row();
+                    }
+                });
+        });
+
+        if let Some(editor) = edit {
+            self.context.contact_editor = Some(editor);
+        }
+
+        if let Some(address_book) = core.address_book.as_mut() {
+            let mut changed = false;
+            if let Some(address) = remove {
+                address_book.remove(&address);
+                changed = true;
+            }
+            if let Some((original, contact)) = saved {
+                // the address of an edited contact may have changed
+                if let Some(original) = original {
+                    address_book.remove(&original);
+                }
+                address_book.insert(contact);
+                changed = true;
+            }
+            if changed {
+                core.store_address_book();
+            }
+        }
+    }
+}
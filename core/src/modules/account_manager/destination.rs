@@ -1,5 +1,6 @@
 use crate::imports::*;
 use super::*;
+use egui_phosphor::light::ADDRESS_BOOK;
 
 pub struct Destination<'context> {
     context : &'context mut ManagerContext,
@@ -10,7 +11,7 @@ impl<'context> Destination<'context> {
         Self { context }
     }
 
-    pub fn render(&mut self, _core: &mut Core, ui : &mut Ui, rc : &RenderContext) {
+    pub fn render(&mut self, core: &mut Core, ui : &mut Ui, rc : &RenderContext) {
         let RenderContext { network_type, .. } = rc;
 
         TextEditor::new(
@@ -26,19 +27,7 @@ impl<'context> Destination<'context> {
             },
         )
         .change(|address| {
-            match Address::try_from(address) {
-                Ok(address) => {
-                    let address_network_type = NetworkType::try_from(address.prefix).expect("prefix to network type");
-                    if address_network_type != *network_type {
-                        self.context.address_status = AddressStatus::NetworkMismatch(address_network_type);
-                    } else {
-                        self.context.address_status = AddressStatus::Valid;
-                    }
-                }
-                Err(err) => {
-                    self.context.address_status = AddressStatus::Invalid(err.to_string());
-                }
-            }
+            self.context.address_status = address_status(address, *network_type);
         })
         .submit(|_, focus|{
             // *focus = Some(Focus::Amount);
@@ -57,6 +46,72 @@ impl<'context> Destination<'context> {
             }
         }
 
+        self.render_contacts(core, ui, rc);
+    }
+
+    fn render_contacts(&mut self, core: &Core, ui : &mut Ui, rc : &RenderContext) {
+        let Some(address_book) = core.address_book.as_ref() else {
+            return;
+        };
+        if address_book.contacts.is_empty() {
+            return;
+        }
+
+        let network = core.network();
+        let mut selected = None;
+
+        if let Some(contact) = address_book.find(&self.context.destination_address_string) {
+            ui.label(format!("{ADDRESS_BOOK} {}", contact.name));
+            if let Some(warning) = contact.warning(network) {
+                ui.colored_label(theme_color().warning_color, warning);
+            }
+        } else if !matches!(self.context.address_status, AddressStatus::Valid) && !self.context.destination_address_string.trim().is_empty() {
+            // autocomplete against contact names, addresses and notes
+            for contact in address_book.search(&self.context.destination_address_string, network).into_iter().take(MAX_SUGGESTIONS) {
+                let text = format!("{ADDRESS_BOOK} {} - {}", contact.name, format_address_string(&contact.address, Some(8)));
+                let response = ui.add(Label::new(text).sense(Sense::click()));
+                let response = match contact.warning(network) {
+                    Some(warning) => response.on_hover_text(warning),
+                    None => response,
+                };
+                if response.clicked() {
+                    selected = Some(contact.address.clone());
+                }
+            }
+        }
+
+        ComboBox::from_id_salt("destination_contacts")
+            .selected_text(i18n("Select contact"))
+            .show_ui(ui, |ui| {
+                for contact in address_book.search("", network) {
+                    if ui.selectable_label(false, format!("{} - {}", contact.name, format_address_string(&contact.address, Some(8)))).clicked() {
+                        selected = Some(contact.address.clone());
+                    }
+                }
+            });
+
+        if let Some(address) = selected {
+            self.context.address_status = address_status(&address, rc.network_type);
+            self.context.destination_address_string = address;
+            self.context.focus.next(Focus::Amount);
+        }
+    }
+}
+
+const MAX_SUGGESTIONS: usize = 5;
 
+pub(super) fn address_status(address: &str, network_type: NetworkType) -> AddressStatus {
+    match Address::try_from(address) {
+        Ok(address) => {
+            let address_network_type = NetworkType::try_from(address.prefix).expect("prefix to network type");
+            if address_network_type != network_type {
+                AddressStatus::NetworkMismatch(address_network_type)
+            } else {
+                AddressStatus::Valid
+            }
+        }
+        Err(err) => {
+            AddressStatus::Invalid(err.to_string())
+        }
     }
 }
\ No newline at end of file
@@ -122,13 +122,14 @@ impl<'context> Estimator<'context> {
         // };
 
 
-        let usd_rate = if core.settings.market_monitor {
-            core.market.as_ref().and_then(|market| {
-                market.price.as_ref().and_then(|price_list| {
-                    price_list.get("usd").map(|market_data| market_data.price)
-                })
-            })
-        } else { None };
+        // fee values are shown in USD unless a fiat currency is selected
+        let fiat = core.fiat().or_else(|| {
+            if core.settings.market_monitor {
+                core.market.as_ref()?.fiat("usd")
+            } else {
+                None
+            }
+        });
 
         let aggregate_mass = actual_estimate.aggregated_fees;
         let number_of_generated_stages = actual_estimate.number_of_generated_transactions;
@@ -159,9 +160,11 @@ impl<'context> Estimator<'context> {
             let feerate = bucket.feerate;
             let seconds = bucket.seconds.max(1.0) * number_of_generated_stages as f64;
             let seconds = if is_send_amount_zero || error.is_some() { "---".to_string() } else { format_duration_estimate_i18n(seconds) };
-            let total_kas = feerate * aggregate_mass as f64 * 1e-8;
             let total_sompi = (feerate * aggregate_mass as f64) as u64;
-            let total_usd = usd_rate.map(|rate| total_kas * rate);
+            let total_fiat = fiat.as_ref().map(|fiat| {
+                let value = sompi_to_tondi(total_sompi) * fiat.price;
+                format!("~{} {}", format_currency(value, 6), fiat.symbol)
+            });
             fee_selection = fee_selection.add_icon_less(mode, i18n(mode.to_string().as_str()), seconds, move |ui| {
                 // icon
                 let icon = if mode == fee_mode {
@@ -172,9 +175,8 @@ impl<'context> Estimator<'context> {
                 ui.label(icon);
                 
                 ui.label(RichText::new(sompi_to_tondi_string_with_suffix(total_sompi, &network_type)).strong());
-                if let Some(usd) = total_usd {
-                    let usd = format_currency(usd, 6);
-                    ui.label(RichText::new(format!("~{} USD", usd)).strong());
+                if let Some(total_fiat) = total_fiat.as_ref() {
+                    ui.label(RichText::new(total_fiat).strong());
                 }
                 ui.label(format!("{} SOMPI/g", format_with_precision(feerate)));
             });
@@ -206,10 +208,14 @@ impl<'context> Estimator<'context> {
             ui.add_space(8.);
 
             if let Some(final_transaction_amount) = actual_estimate.final_transaction_amount {
+                let final_amount = final_transaction_amount + actual_estimate.aggregated_fees;
                 ui.heading(RichText::new(
                     i18n_args("Final Amount: {amount}", 
-                        &[("amount",sompi_to_tondi_string_with_suffix(final_transaction_amount + actual_estimate.aggregated_fees, &network_type))]
+                        &[("amount",sompi_to_tondi_string_with_suffix(final_amount, &network_type))]
                     )).strong());
+                if let Some(fiat) = core.fiat() {
+                    ui.label(fiat.format(final_amount));
+                }
             }
 
         });
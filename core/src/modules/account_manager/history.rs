@@ -0,0 +1,307 @@
+use crate::imports::*;
+use chrono::{Days, Local, NaiveDate};
+use tondi_wallet_core::storage::TransactionKind;
+
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq)]
+pub enum TransactionDirection {
+    #[default]
+    All,
+    Incoming,
+    Outgoing,
+    Transfer,
+    Change,
+}
+
+impl TransactionDirection {
+    pub fn iter() -> impl Iterator<Item = TransactionDirection> {
+        [
+            TransactionDirection::All,
+            TransactionDirection::Incoming,
+            TransactionDirection::Outgoing,
+            TransactionDirection::Transfer,
+            TransactionDirection::Change,
+        ]
+        .into_iter()
+    }
+
+    pub fn describe(&self) -> &'static str {
+        match self {
+            TransactionDirection::All => i18n("All"),
+            TransactionDirection::Incoming => i18n("Incoming"),
+            TransactionDirection::Outgoing => i18n("Outgoing"),
+            TransactionDirection::Transfer => i18n("Transfer"),
+            TransactionDirection::Change => i18n("Change"),
+        }
+    }
+
+    pub fn matches(&self, kind: TransactionKind) -> bool {
+        match self {
+            TransactionDirection::All => true,
+            TransactionDirection::Incoming => matches!(kind, TransactionKind::Incoming | TransactionKind::External),
+            TransactionDirection::Outgoing => matches!(kind, TransactionKind::Outgoing | TransactionKind::Batch),
+            TransactionDirection::Transfer => matches!(kind, TransactionKind::TransferIncoming | TransactionKind::TransferOutgoing),
+            TransactionDirection::Change => matches!(kind, TransactionKind::Change),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn describe_transaction_kind(kind: TransactionKind) -> &'static str {
+    match kind {
+        TransactionKind::Incoming => "incoming",
+        TransactionKind::Outgoing => "outgoing",
+        TransactionKind::External => "external",
+        TransactionKind::Reorg => "reorg",
+        TransactionKind::Batch => "batch",
+        TransactionKind::Stasis => "stasis",
+        TransactionKind::TransferIncoming => "transfer-incoming",
+        TransactionKind::TransferOutgoing => "transfer-outgoing",
+        TransactionKind::Change => "change",
+    }
+}
+
+/// User-editable transaction history filter.
+#[derive(Default, Clone, Eq, PartialEq)]
+pub struct HistoryFilter {
+    pub date_from: String,
+    pub date_to: String,
+    pub direction: TransactionDirection,
+    pub amount_min: String,
+    pub amount_max: String,
+    pub address: String,
+    pub search: String,
+    /// Page offset within the filtered history.
+    pub start: u64,
+}
+
+impl HistoryFilter {
+    pub fn is_active(&self) -> bool {
+        self.direction != TransactionDirection::All
+            || [&self.date_from, &self.date_to, &self.amount_min, &self.amount_max, &self.address, &self.search]
+                .iter()
+                .any(|text| !text.trim().is_empty())
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn criteria(&self) -> Result<HistoryCriteria> {
+        let from = parse_date(&self.date_from)?.map(local_midnight_msec).transpose()?;
+        // the end date is inclusive
+        let to = parse_date(&self.date_to)?
+            .map(|date| date.checked_add_days(Days::new(1)).ok_or_else(|| Error::custom(i18n("Invalid date"))))
+            .transpose()?
+            .map(local_midnight_msec)
+            .transpose()?;
+
+        let amount_min = try_tondi_str_to_sompi(self.amount_min.as_str())
+            .map_err(|err| Error::custom(format!("{} {err}", i18n("Invalid amount:"))))?;
+        let amount_max = try_tondi_str_to_sompi(self.amount_max.as_str())
+            .map_err(|err| Error::custom(format!("{} {err}", i18n("Invalid amount:"))))?;
+
+        let address = Some(self.address.trim().to_lowercase()).filter(|address| !address.is_empty());
+        let search = Some(self.search.trim().to_lowercase()).filter(|search| !search.is_empty());
+
+        Ok(HistoryCriteria {
+            from,
+            to,
+            direction: self.direction,
+            amount_min,
+            amount_max,
+            address,
+            search,
+        })
+    }
+}
+
+fn parse_date(text: &str) -> Result<Option<NaiveDate>> {
+    let text = text.trim();
+    if text.is_empty() {
+        Ok(None)
+    } else {
+        NaiveDate::parse_from_str(text, "%Y-%m-%d")
+            .map(Some)
+            .map_err(|_| Error::custom(i18n_args("Invalid date '{date}', expected YYYY-MM-DD", &[("date", text)])))
+    }
+}
+
+fn local_midnight_msec(date: NaiveDate) -> Result<u64> {
+    date.and_hms_opt(0, 0, 0)
+        .and_then(|datetime| datetime.and_local_timezone(Local).earliest())
+        .map(|datetime| datetime.timestamp_millis().max(0) as u64)
+        .ok_or_else(|| Error::custom(i18n("Invalid date")))
+}
+
+/// Parsed [`HistoryFilter`].
+#[derive(Debug, Clone)]
+pub struct HistoryCriteria {
+    pub from: Option<u64>,
+    pub to: Option<u64>,
+    pub direction: TransactionDirection,
+    pub amount_min: Option<u64>,
+    pub amount_max: Option<u64>,
+    pub address: Option<String>,
+    pub search: Option<String>,
+}
+
+impl HistoryCriteria {
+    pub fn matches(&self, transaction: &Transaction, network: Network, notes: Option<&AccountNotes>) -> bool {
+        let record = transaction.record();
+
+        if self.from.is_some() || self.to.is_some() {
+            let Some(timestamp) = record.unixtime_msec else {
+                return false;
+            };
+            if self.from.is_some_and(|from| timestamp < from) || self.to.is_some_and(|to| timestamp >= to) {
+                return false;
+            }
+        }
+
+        if !self.direction.matches(record.transaction_data().kind()) {
+            return false;
+        }
+
+        let value = record.value();
+        if self.amount_min.is_some_and(|min| value < min) || self.amount_max.is_some_and(|max| value > max) {
+            return false;
+        }
+
+        let addresses = if self.address.is_some() || self.search.is_some() {
+            transaction.addresses(network)
+        } else {
+            vec![]
+        };
+
+        if let Some(address) = self.address.as_ref() {
+            if !addresses.iter().any(|candidate| candidate.to_string().to_lowercase().contains(address)) {
+                return false;
+            }
+        }
+
+        if let Some(search) = self.search.as_ref() {
+            let id = record.id();
+            if !id.to_string().contains(search) && !notes.is_some_and(|notes| notes.matches(id, &addresses, search)) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+/// Transaction and address note editor state.
+#[derive(Clone)]
+pub struct NoteEditor {
+    pub transaction_id: TransactionId,
+    pub text: String,
+    pub tags: String,
+    pub address: Option<Address>,
+    pub address_text: String,
+    pub address_tags: String,
+}
+
+impl NoteEditor {
+    pub fn new(transaction_id: TransactionId, address: Option<Address>, notes: Option<&AccountNotes>) -> Self {
+        let note = notes.and_then(|notes| notes.transaction(&transaction_id)).cloned().unwrap_or_default();
+        let mut editor = Self {
+            transaction_id,
+            text: note.text,
+            tags: note.tags_as_string(),
+            address: None,
+            address_text: String::default(),
+            address_tags: String::default(),
+        };
+        editor.select_address(address, notes);
+        editor
+    }
+
+    pub fn select_address(&mut self, address: Option<Address>, notes: Option<&AccountNotes>) {
+        let note = address.as_ref()
+            .and_then(|address| notes.and_then(|notes| notes.address(address)))
+            .cloned()
+            .unwrap_or_default();
+        self.address = address;
+        self.address_text = note.text;
+        self.address_tags = note.tags_as_string();
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+/// Serialize transactions into CSV with amounts in TONDI.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn export_transactions_csv(transactions: &[&Transaction], network: Network, notes: Option<&AccountNotes>) -> String {
+    let mut csv = String::from("transaction_id,kind,timestamp,datetime,block_daa_score,value,fees,addresses,note,tags\n");
+    for transaction in transactions {
+        let record = transaction.record();
+        let timestamp = record.unixtime_msec.map(|timestamp| timestamp.to_string()).unwrap_or_default();
+        let datetime = record
+            .unixtime_msec
+            .and_then(|timestamp| chrono::DateTime::<chrono::Utc>::from_timestamp_millis(timestamp as i64))
+            .map(|datetime| datetime.to_rfc3339())
+            .unwrap_or_default();
+        let fees = transaction.fees().map(|fees| format!("{:.8}", sompi_to_tondi(fees))).unwrap_or_default();
+        let addresses = transaction
+            .addresses(network)
+            .iter()
+            .map(|address| address.to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let note = notes.and_then(|notes| notes.transaction(record.id())).cloned().unwrap_or_default();
+
+        csv.push_str(&format!(
+            "{},{},{},{},{},{:.8},{},{},{},{}\n",
+            record.id(),
+            describe_transaction_kind(record.transaction_data().kind()),
+            timestamp,
+            datetime,
+            record.block_daa_score(),
+            sompi_to_tondi(record.value()),
+            fees,
+            csv_field(&addresses),
+            csv_field(&note.text),
+            csv_field(&note.tags.join(" ")),
+        ));
+    }
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_history_filter() {
+        let mut filter = HistoryFilter::default();
+        assert!(!filter.is_active());
+
+        filter.direction = TransactionDirection::Outgoing;
+        assert!(filter.is_active());
+        assert!(TransactionDirection::Outgoing.matches(TransactionKind::Batch));
+        assert!(!TransactionDirection::Outgoing.matches(TransactionKind::Incoming));
+
+        filter.date_from = "2024-01-31".to_string();
+        filter.date_to = "2024-02-01".to_string();
+        let criteria = filter.criteria().unwrap();
+        let (from, to) = (criteria.from.unwrap(), criteria.to.unwrap());
+        assert_eq!(to - from, 2 * 24 * 60 * 60 * 1000);
+
+        filter.date_to = "02/01/2024".to_string();
+        assert!(filter.criteria().is_err());
+    }
+
+    #[test]
+    fn test_csv_field() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+}
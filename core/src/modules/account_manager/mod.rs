@@ -9,9 +9,12 @@ use crate::primitives::descriptor::*;
 
 mod address;
 mod balance;
+mod consolidation;
+mod contacts;
 mod destination;
 mod details;
 mod estimator;
+mod history;
 pub mod menus;
 mod network;
 mod overview;
@@ -24,9 +27,12 @@ mod utxo;
 
 use address::*;
 use balance::*;
+use consolidation::*;
+use contacts::*;
 use destination::*;
 use details::*;
 use estimator::*;
+use history::*;
 use menus::*;
 use network::*;
 use overview::*;
@@ -35,6 +41,7 @@ use qr::*;
 use secret::*;
 use transactions::*;
 use transfer::*;
+use utxo::*;
 
 
 #[allow(dead_code)]
@@ -51,7 +58,8 @@ pub enum AccountManagerSection {
     Overview,
     Transactions,
     Details,
-    // UtxoManager
+    UtxoManager,
+    Contacts,
 }
 
 // #[derive(Default, Debug, Clone, Copy, Eq, PartialEq)]
@@ -183,7 +191,13 @@ pub struct ManagerContext {
     wallet_secret : String,
     payment_secret : String,
     loading : bool,
-    fee_mode : FeeMode
+    fee_mode : FeeMode,
+    history : HistoryFilter,
+    show_history_filter : bool,
+    history_loaded : Option<(AccountId, u64)>,
+    note_editor : Option<NoteEditor>,
+    contact_editor : Option<ContactEditor>,
+    contact_search : String,
 }
 
 impl ManagerContext {
@@ -252,6 +266,7 @@ pub struct AccountManager {
     state: AccountManagerState,
     section: AccountManagerSection,
     context : ManagerContext,
+    utxo_manager : UtxoManager,
 }
 
 impl AccountManager {
@@ -261,6 +276,7 @@ impl AccountManager {
             state: AccountManagerState::Select,
             section: AccountManagerSection::Overview,
             context : ManagerContext::default(),
+            utxo_manager : UtxoManager::new(),
         }
     }
 }
@@ -269,6 +285,7 @@ impl ModuleT for AccountManager {
 
     fn reset(&mut self, _core: &mut Core) {
         self.context = ManagerContext::default();
+        self.utxo_manager = UtxoManager::new();
         self.state = AccountManagerState::Select;
     }
 
@@ -506,12 +523,17 @@ impl AccountManager {
                             self.section = AccountManagerSection::Details;
                         }
 
-                        // if core.device().desktop() {
-                        //     ui.separator();
-                        //     if ui.add(Label::new(i18n("UTXOs")).sense(Sense::click())).clicked() {
-                        //         self.section = AccountManagerSection::UtxoManager;
-                        //     }
-                        // }
+                        ui.separator();
+                        if ui.add(Label::new(i18n("Contacts")).sense(Sense::click())).clicked() {
+                            self.section = AccountManagerSection::Contacts;
+                        }
+
+                        if core.device().desktop() {
+                            ui.separator();
+                            if ui.add(Label::new(i18n("UTXOs")).sense(Sense::click())).clicked() {
+                                self.section = AccountManagerSection::UtxoManager;
+                            }
+                        }
 
                     });
 
@@ -535,12 +557,17 @@ impl AccountManager {
                     ui.separator();
                     ToolsMenu::new().render(core,ui,self, rc, screen_rect_height * 0.8);
 
-                    // if core.device().desktop() {
-                    //     ui.separator();
-                    //     if ui.add(Label::new(i18n("UTXOs")).sense(Sense::click())).clicked() {
-                    //         self.section = AccountManagerSection::UtxoManager;
-                    //     }
-                    // }
+                    ui.separator();
+                    if ui.add(Label::new(i18n("Contacts")).sense(Sense::click())).clicked() {
+                        self.section = AccountManagerSection::Contacts;
+                    }
+
+                    if core.device().desktop() {
+                        ui.separator();
+                        if ui.add(Label::new(i18n("UTXOs")).sense(Sense::click())).clicked() {
+                            self.section = AccountManagerSection::UtxoManager;
+                        }
+                    }
 
                     ui.separator();
                     if ui.add(Label::new(i18n("Details")).sense(Sense::click())).clicked() {
@@ -590,14 +617,17 @@ impl AccountManager {
                         Overview::new(&mut self.context).render(core,ui,rc);
                     }
                     AccountManagerSection::Transactions => {
-                        Transactions::new().render(ui,core,rc);
+                        Transactions::new(&mut self.context).render(ui,core,rc);
                     }
                     AccountManagerSection::Details => {
                         Details::new().render(core,ui,rc);
                     }
-                    // AccountManagerSection::UtxoManager => {
-                    //     UtxoManager::new().render(core,ui,rc);
-                    // }
+                    AccountManagerSection::UtxoManager => {
+                        self.utxo_manager.render(core,ui,rc);
+                    }
+                    AccountManagerSection::Contacts => {
+                        Contacts::new(&mut self.context).render(core,ui,rc);
+                    }
                 }
             });
 
@@ -611,14 +641,17 @@ impl AccountManager {
                 Overview::new(&mut self.context).render(core,ui,rc);
             }
             AccountManagerSection::Transactions => {
-                Transactions::new().render(ui,core,rc);
+                Transactions::new(&mut self.context).render(ui,core,rc);
             }
             AccountManagerSection::Details => {
                 Details::new().render(core,ui,rc);
             }
-            // AccountManagerSection::UtxoManager => {
-            //     UtxoManager::new().render(core,ui,rc);
-            // }
+            AccountManagerSection::UtxoManager => {
+                self.utxo_manager.render(core,ui,rc);
+            }
+            AccountManagerSection::Contacts => {
+                Contacts::new(&mut self.context).render(core,ui,rc);
+            }
         }
 
     }
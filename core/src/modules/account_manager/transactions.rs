@@ -1,17 +1,329 @@
 use crate::imports::*;
 use super::*;
 use crate::core::TRANSACTION_PAGE_SIZE;
+use egui_phosphor::light::{ADDRESS_BOOK, FUNNEL, NOTE_PENCIL, USER_PLUS};
 
-pub struct Transactions { }
+pub struct Transactions<'context> {
+    context : &'context mut ManagerContext,
+}
 
-impl Transactions {
-    pub fn new() -> Self {
-        Self { }
+impl<'context> Transactions<'context> {
+    pub fn new(context : &'context mut ManagerContext) -> Self {
+        Self { context }
     }
 
     pub fn render(&mut self, ui: &mut Ui, core : &mut Core, rc : &RenderContext) {
         let RenderContext { account, network_type, current_daa_score, .. } = rc;
+
+        ui.horizontal(|ui| {
+            let text = if self.context.history.is_active() {
+                RichText::new(format!("{FUNNEL} {}", i18n("Filter"))).strong()
+            } else {
+                RichText::new(format!("{FUNNEL} {}", i18n("Filter")))
+            };
+            if ui.add(Label::new(text).sense(Sense::click())).clicked() {
+                self.context.show_history_filter = !self.context.show_history_filter;
+            }
+        });
+
+        if !self.context.show_history_filter {
+            if self.context.history_loaded.take().is_some() {
+                // restore the paged view after the full history was loaded
+                core.load_account_transactions_with_range(account, 0..TRANSACTION_PAGE_SIZE)
+                    .map_err(|err|{
+                        log_info!("Failed to load transactions\n{err:?}")
+                    }).ok();
+                account.set_transaction_start(0);
+            }
+
+            self.render_page(ui, core, rc);
+            return;
+        }
+
+        // the filter is applied to the full history of the account
+        let history_state = (account.id(), account.transaction_count());
+        if self.context.history_loaded != Some(history_state) {
+            core.load_account_transactions_with_range(account, 0..account.transaction_count())
+                .map_err(|err|{
+                    log_info!("Failed to load transactions\n{err:?}")
+                }).ok();
+            account.set_transaction_start(0);
+            self.context.history_loaded = Some(history_state);
+        }
+
+        self.render_filter(ui);
+
+        let criteria = match self.context.history.criteria() {
+            Ok(criteria) => criteria,
+            Err(err) => {
+                ui.colored_label(theme_color().error_color, err.to_string());
+                return;
+            }
+        };
+
+        let network = account.network();
+        let notes = core.wallet_notes.as_ref().and_then(|notes| notes.account(&account.id()));
+        let account_transactions = account.transactions();
+        let transactions = account_transactions.iter()
+            .filter(|transaction| criteria.matches(transaction, network, notes))
+            .collect::<Vec<_>>();
+
+        ui.horizontal(|ui| {
+            ui.label(i18n_args("{count} of {total} transactions", &[
+                ("count", transactions.len().separated_string()),
+                ("total", account.transaction_count().separated_string()),
+            ]));
+
+            #[cfg(not(target_arch = "wasm32"))]
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui.add_enabled(!transactions.is_empty(), Button::new(format!("{} {}", egui_phosphor::light::EXPORT, i18n("Export CSV")))).clicked() {
+                    export(&transactions, network, notes);
+                }
+            });
+        });
+
+        ui.separator();
+
         let max_height = ui.available_height() - (ui.fonts(|fonts|RichText::new("YWgy").font_height(fonts, ui.style())).at_least(ui.spacing().interact_size.y) * 2.0 + 5.0);
+        let start = self.context.history.start.min(transactions.len() as u64);
+        let mut saved_notes = None;
+        let mut saved_contact = None;
+        egui::ScrollArea::vertical().max_height(max_height).auto_shrink([false,false]).show(ui, |ui| {
+            if transactions.is_empty() {
+                ui.vertical_centered(|ui| {
+                    ui.label("");
+                    ui.label(RichText::new(i18n("No matching transactions")).size(16.));
+                });
+            } else {
+                let total: u64 = transactions.iter().map(|transaction|transaction.aggregate_input_value()).sum();
+                let fiat = core.fiat();
+                transactions.iter().skip(start as usize).take(TRANSACTION_PAGE_SIZE as usize).for_each(|transaction| {
+                    transaction.render(ui, *network_type, network, *current_daa_score, true, Some(total), fiat.as_ref());
+                    if let Some(editor) = self.render_notes(ui, core, account, transaction) {
+                        saved_notes = Some(editor);
+                    }
+                    if let Some(contact) = self.render_contact_editor(ui, core, account, transaction) {
+                        saved_contact = Some(contact);
+                    }
+                });
+            }
+        });
+
+        if let Some(editor) = saved_notes {
+            Self::save_notes(core, account, editor);
+        }
+
+        if let Some(contact) = saved_contact {
+            Self::save_contact(core, contact);
+        }
+
+        ui.add_space(4.);
+        let pagination = Pagination::new(transactions.len() as u64, Some(start), Some(TRANSACTION_PAGE_SIZE), Some(5));
+        if let Some(start) = pagination.render(ui) {
+            self.context.history.start = start;
+            runtime().request_repaint();
+        }
+    }
+
+    fn render_filter(&mut self, ui: &mut Ui) {
+        let history = &mut self.context.history;
+        let before = history.clone();
+
+        egui::Grid::new("transaction_history_filter")
+            .num_columns(2)
+            .spacing([8.,4.])
+            .show(ui, |ui| {
+                ui.label(i18n("Date:"));
+                ui.horizontal(|ui| {
+                    ui.add(TextEdit::singleline(&mut history.date_from).hint_text("YYYY-MM-DD").desired_width(96.));
+                    ui.label("-");
+                    ui.add(TextEdit::singleline(&mut history.date_to).hint_text("YYYY-MM-DD").desired_width(96.));
+                });
+                ui.end_row();
+
+                ui.label(i18n("Direction:"));
+                ComboBox::from_id_salt("transaction_history_direction")
+                    .selected_text(history.direction.describe())
+                    .show_ui(ui, |ui| {
+                        for direction in TransactionDirection::iter() {
+                            ui.selectable_value(&mut history.direction, direction, direction.describe());
+                        }
+                    });
+                ui.end_row();
+
+                ui.label(i18n("Amount:"));
+                ui.horizontal(|ui| {
+                    ui.add(TextEdit::singleline(&mut history.amount_min).hint_text(i18n("min")).desired_width(96.));
+                    ui.label("-");
+                    ui.add(TextEdit::singleline(&mut history.amount_max).hint_text(i18n("max")).desired_width(96.));
+                });
+                ui.end_row();
+
+                ui.label(i18n("Address:"));
+                ui.add(TextEdit::singleline(&mut history.address).desired_width(f32::INFINITY));
+                ui.end_row();
+
+                ui.label(i18n("Search:"));
+                ui.add(TextEdit::singleline(&mut history.search).hint_text(i18n("Transaction ID")).desired_width(f32::INFINITY));
+                ui.end_row();
+            });
+
+        ui.horizontal(|ui| {
+            if ui.add_enabled(history.is_active(), Button::new(i18n("Clear"))).clicked() {
+                history.clear();
+            }
+        });
+
+        // restart paging whenever the filter changes
+        if *history != before {
+            history.start = 0;
+        }
+    }
+
+    /// Show the transaction and counterparty address notes, or the note editor.
+    /// Returns the editor state once the user saves the notes.
+    fn render_notes(&mut self, ui: &mut Ui, core : &Core, account : &Account, transaction : &Transaction) -> Option<NoteEditor> {
+        // notes are editable only once loaded
+        let wallet_notes = core.wallet_notes.as_ref()?;
+
+        let transaction_id = transaction.id();
+        let notes = wallet_notes.account(&account.id());
+        let addresses = transaction.addresses(account.network());
+
+        let editor = self.context.note_editor.as_mut().filter(|editor| editor.transaction_id == transaction_id);
+        let Some(editor) = editor else {
+            ui.horizontal_wrapped(|ui| {
+                if let Some(note) = notes.and_then(|notes| notes.transaction(&transaction_id)) {
+                    ui.label(RichText::new(note.to_string()).color(theme_color().strong_color));
+                }
+                for address in addresses.iter() {
+                    if let Some(note) = notes.and_then(|notes| notes.address(address)) {
+                        ui.label(format!("{}: {note}", format_address(address, Some(6))));
+                    }
+                }
+                if ui.add(Label::new(RichText::new(NOTE_PENCIL).size(14.)).sense(Sense::click()))
+                    .on_hover_text(i18n("Edit notes"))
+                    .clicked() {
+                    self.context.note_editor = Some(NoteEditor::new(transaction_id, addresses.first().cloned(), notes));
+                }
+                if let Some(address_book) = core.address_book.as_ref() {
+                    for address in addresses.iter() {
+                        if let Some(contact) = address_book.find(&address.to_string()) {
+                            ui.label(format!("{ADDRESS_BOOK} {}", contact.name)).on_hover_text(address.to_string());
+                        }
+                    }
+                    let candidate = addresses.iter().find(|address| address_book.find(&address.to_string()).is_none());
+                    if let Some(address) = candidate {
+                        if ui.add(Label::new(RichText::new(USER_PLUS).size(14.)).sense(Sense::click()))
+                            .on_hover_text(i18n("Add to contacts"))
+                            .clicked() {
+                            self.context.contact_editor = Some(ContactEditor::with_transaction(transaction_id, address, account.network()));
+                        }
+                    }
+                }
+            });
+            return None;
+        };
+
+        let mut save = false;
+        let mut cancel = false;
+
+        ui.add_space(4.);
+        egui::Grid::new(format!("note_editor_{transaction_id}"))
+            .num_columns(2)
+            .spacing([8.,4.])
+            .show(ui, |ui| {
+                ui.label(i18n("Note:"));
+                ui.add(TextEdit::singleline(&mut editor.text).desired_width(f32::INFINITY));
+                ui.end_row();
+
+                ui.label(i18n("Tags:"));
+                ui.add(TextEdit::singleline(&mut editor.tags).hint_text(i18n("comma separated")).desired_width(f32::INFINITY));
+                ui.end_row();
+
+                if !addresses.is_empty() {
+                    ui.label(i18n("Address:"));
+                    let mut selected = editor.address.clone();
+                    ComboBox::from_id_salt(format!("note_editor_address_{transaction_id}"))
+                        .selected_text(selected.as_ref().map(|address| format_address(address, Some(8))).unwrap_or_default())
+                        .show_ui(ui, |ui| {
+                            for address in addresses.iter() {
+                                ui.selectable_value(&mut selected, Some(address.clone()), format_address(address, Some(8)));
+                            }
+                        });
+                    if selected != editor.address {
+                        editor.select_address(selected, notes);
+                    }
+                    ui.end_row();
+
+                    ui.label(i18n("Address note:"));
+                    ui.add(TextEdit::singleline(&mut editor.address_text).desired_width(f32::INFINITY));
+                    ui.end_row();
+
+                    ui.label(i18n("Address tags:"));
+                    ui.add(TextEdit::singleline(&mut editor.address_tags).hint_text(i18n("comma separated")).desired_width(f32::INFINITY));
+                    ui.end_row();
+                }
+            });
+
+        ui.horizontal(|ui| {
+            if ui.medium_button(i18n("Save")).clicked() {
+                save = true;
+            }
+            if ui.medium_button(i18n("Cancel")).clicked() {
+                cancel = true;
+            }
+        });
+        ui.add_space(4.);
+
+        if save {
+            self.context.note_editor.take()
+        } else {
+            if cancel {
+                self.context.note_editor = None;
+            }
+            None
+        }
+    }
+
+    fn save_notes(core : &mut Core, account : &Account, editor : NoteEditor) {
+        if let Some(wallet_notes) = core.wallet_notes.as_mut() {
+            let notes = wallet_notes.account_mut(&account.id());
+            notes.set_transaction(&editor.transaction_id, Note::new(&editor.text, &editor.tags));
+            if let Some(address) = editor.address.as_ref() {
+                notes.set_address(address, Note::new(&editor.address_text, &editor.address_tags));
+            }
+            core.store_wallet_notes();
+        }
+    }
+
+    /// Show the contact editor opened from the given transaction.
+    /// Returns the contact once the user saves it.
+    fn render_contact_editor(&mut self, ui: &mut Ui, core : &Core, account : &Account, transaction : &Transaction) -> Option<Contact> {
+        let transaction_id = transaction.id();
+        let editor = self.context.contact_editor.as_mut().filter(|editor| editor.transaction_id == Some(transaction_id))?;
+
+        let addresses = transaction.addresses(account.network());
+        let mut closed = false;
+        let contact = editor.render(ui, core.network(), &addresses, &mut closed);
+        if closed {
+            self.context.contact_editor = None;
+        }
+        contact
+    }
+
+    fn save_contact(core : &mut Core, contact : Contact) {
+        if let Some(address_book) = core.address_book.as_mut() {
+            address_book.insert(contact);
+            core.store_address_book();
+        }
+    }
+
+    fn render_page(&mut self, ui: &mut Ui, core : &mut Core, rc : &RenderContext) {
+        let RenderContext { account, network_type, current_daa_score, .. } = rc;
+        let max_height = ui.available_height() - (ui.fonts(|fonts|RichText::new("YWgy").font_height(fonts, ui.style())).at_least(ui.spacing().interact_size.y) * 2.0 + 5.0);
+        let mut saved_notes = None;
+        let mut saved_contact = None;
         egui::ScrollArea::vertical().max_height(max_height).auto_shrink([false,false]).show(ui, |ui| {
             let transactions = account.transactions();
             if transactions.is_empty() {
@@ -21,12 +333,27 @@ impl Transactions {
                 });
             } else {
                 let total: u64 = transactions.iter().map(|transaction|transaction.aggregate_input_value()).sum();
+                let fiat = core.fiat();
                 transactions.iter().for_each(|transaction| {
-                    transaction.render(ui, *network_type, account.network(), *current_daa_score, true, Some(total));
+                    transaction.render(ui, *network_type, account.network(), *current_daa_score, true, Some(total), fiat.as_ref());
+                    if let Some(editor) = self.render_notes(ui, core, account, transaction) {
+                        saved_notes = Some(editor);
+                    }
+                    if let Some(contact) = self.render_contact_editor(ui, core, account, transaction) {
+                        saved_contact = Some(contact);
+                    }
                 });
             }
         });
 
+        if let Some(editor) = saved_notes {
+            Self::save_notes(core, account, editor);
+        }
+
+        if let Some(contact) = saved_contact {
+            Self::save_contact(core, contact);
+        }
+
         ui.add_space(4.);
         let pagination = Pagination::new(account.transaction_count(), account.transaction_start().into(), Some(TRANSACTION_PAGE_SIZE), Some(5));
         if let Some(start) = pagination.render(ui){
@@ -40,3 +367,27 @@ impl Transactions {
         }
     }
 }
+
+#[cfg(not(target_arch = "wasm32"))]
+fn export(transactions: &[&Transaction], network: Network, notes: Option<&AccountNotes>) {
+    let Some(mut path) = rfd::FileDialog::new()
+        .add_filter("CSV", &["csv"])
+        .set_file_name(format!("tondi-transactions-{network}.csv"))
+        .save_file()
+    else {
+        return;
+    };
+    path.set_extension("csv");
+
+    match std::fs::write(&path, export_transactions_csv(transactions, network, notes)) {
+        Ok(()) => {
+            runtime().notify(UserNotification::success(i18n_args(
+                "Transactions exported to {path}",
+                &[("path", path.to_string_lossy())],
+            )).as_toast());
+        }
+        Err(err) => {
+            runtime().error(format!("{}: {err}", i18n("Unable to export transactions")));
+        }
+    }
+}
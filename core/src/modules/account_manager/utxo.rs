@@ -1,27 +1,574 @@
-#![allow(dead_code)]
-
 use crate::imports::*;
 use super::*;
+use egui_phosphor::light::{ARROWS_CLOCKWISE, CARET_DOWN, CARET_UP, CHECK, PAPER_PLANE_TILT, X};
+use futures::TryStreamExt;
+use tondi_wallet_core::tx::{Generator, GeneratorSettings, PaymentDestination, Signer};
+use tondi_wallet_core::utxo::{NetworkParams, UtxoEntryReference};
+
+/// UTXO outpoint used to track the selection (transaction id, output index).
+pub type Outpoint = (TransactionId, u32);
+
+#[derive(Debug, Clone)]
+pub struct UtxoRecord {
+    pub outpoint: Outpoint,
+    pub address: Option<Address>,
+    pub amount: u64,
+    pub block_daa_score: u64,
+    pub is_coinbase: bool,
+}
+
+impl UtxoRecord {
+    /// Maturity progress in the range `0.0..1.0` or `None` if the UTXO is mature.
+    pub fn maturity_progress(&self, params: &NetworkParams, current_daa_score: u64) -> Option<f64> {
+        let period = if self.is_coinbase {
+            params.coinbase_transaction_maturity_period_daa()
+        } else {
+            params.user_transaction_maturity_period_daa()
+        };
+
+        let confirmations = current_daa_score.saturating_sub(self.block_daa_score);
+        (confirmations < period).then(|| confirmations as f64 / period as f64)
+    }
+
+    /// `true` if the UTXO is mature and can be spent; UTXOs are treated as
+    /// immature while the current DAA score is unknown.
+    pub fn is_mature(&self, params: &NetworkParams, current_daa_score: Option<u64>) -> bool {
+        current_daa_score.is_some_and(|current_daa_score| self.maturity_progress(params, current_daa_score).is_none())
+    }
+}
+
+#[derive(Default, Debug, Clone, Copy, Eq, PartialEq)]
+pub enum UtxoSort {
+    #[default]
+    Amount,
+    DaaScore,
+    Address,
+    Coinbase,
+}
+
+impl UtxoSort {
+    pub fn sort(&self, utxos: &mut [UtxoRecord], ascending: bool) {
+        match self {
+            UtxoSort::Amount => utxos.sort_by_key(|utxo| utxo.amount),
+            UtxoSort::DaaScore => utxos.sort_by_key(|utxo| utxo.block_daa_score),
+            UtxoSort::Address => utxos.sort_by_key(|utxo| utxo.address.as_ref().map(|address| address.to_string())),
+            UtxoSort::Coinbase => utxos.sort_by_key(|utxo| utxo.is_coinbase),
+        }
 
+        if !ascending {
+            utxos.reverse();
+        }
+    }
+}
+
+#[derive(Default, Clone)]
+enum UtxoAction {
+    #[default]
+    None,
+    Send,
+    Processing,
+    Sent(GeneratorSummary),
+    Error(String),
+}
+
+#[derive(Default)]
 pub struct UtxoManager {
+    account_id: Option<AccountId>,
+    utxo_count: Option<usize>,
+    utxos: Vec<UtxoRecord>,
+    selected: AHashSet<Outpoint>,
+    sort: UtxoSort,
+    ascending: bool,
+    action: UtxoAction,
+    consolidation: Consolidation,
+    destination_address_string: String,
+    send_amount_text: String,
+    priority_fees_text: String,
+    wallet_secret: String,
+    payment_secret: String,
+}
+
+impl Zeroize for UtxoManager {
+    fn zeroize(&mut self) {
+        self.destination_address_string = String::default();
+        self.send_amount_text = String::default();
+        self.priority_fees_text = String::default();
+        self.wallet_secret.zeroize();
+        self.payment_secret.zeroize();
+    }
 }
 
 impl UtxoManager {
     pub fn new() -> Self {
-        Self { }
+        Self::default()
+    }
+
+    pub fn selected(&self) -> impl Iterator<Item = &UtxoRecord> {
+        self.utxos.iter().filter(|utxo| self.selected.contains(&utxo.outpoint))
     }
 
-    pub fn render(&mut self, _core: &mut Core, ui : &mut Ui, rc : &RenderContext) {
-        let RenderContext { account: _, .. } = rc;
+    fn selected_amount(&self) -> u64 {
+        self.selected().map(|utxo| utxo.amount).sum()
+    }
+
+    fn refresh(&mut self, account_id: AccountId) {
+        let utxo_result = Payload::<Result<(AccountId, Vec<UtxoRecord>)>>::new("utxo_manager_utxos");
+        if !utxo_result.is_pending() {
+            spawn_with_result(&utxo_result, async move {
+                let request = AccountsGetUtxosRequest {
+                    account_id,
+                    addresses: None,
+                    min_amount_sompi: None,
+                };
+
+                let utxos = runtime().wallet().accounts_get_utxos_call(request).await?.utxos;
+                runtime().request_repaint();
+                let utxos = utxos.into_iter().map(|utxo| UtxoRecord {
+                    outpoint: (utxo.outpoint.transaction_id, utxo.outpoint.index),
+                    address: utxo.address,
+                    amount: utxo.amount,
+                    block_daa_score: utxo.block_daa_score,
+                    is_coinbase: utxo.is_coinbase,
+                }).collect();
+
+                Ok((account_id, utxos))
+            });
+        }
+    }
+
+    /// Reload UTXOs when the account changes or when its UTXO count changes.
+    fn update(&mut self, account: &Account) {
+        let account_id = account.id();
+        let utxo_count = account.balance().map(|balance| balance.mature_utxo_count + balance.pending_utxo_count);
+
+        if self.account_id != Some(account_id) {
+            self.account_id = Some(account_id);
+            self.utxos.clear();
+            self.selected.clear();
+            self.action = UtxoAction::None;
+            self.consolidation.close();
+            self.zeroize();
+        } else if self.utxo_count == utxo_count {
+            return;
+        }
+
+        self.utxo_count = utxo_count;
+        self.refresh(account_id);
+    }
+
+    pub fn render(&mut self, core: &mut Core, ui : &mut Ui, rc : &RenderContext) {
+        let RenderContext { account, network_type, current_daa_score, .. } = rc;
+
+        let params: &'static NetworkParams = core.network().into();
+
+        self.update(account);
+
+        let utxo_result = Payload::<Result<(AccountId, Vec<UtxoRecord>)>>::new("utxo_manager_utxos");
+        if let Some(result) = utxo_result.take() {
+            match result {
+                Ok((account_id, _)) if Some(account_id) != self.account_id => {
+                    self.refresh(account.id());
+                }
+                Ok((_, mut utxos)) => {
+                    self.sort.sort(&mut utxos, self.ascending);
+                    let outpoints = utxos.iter().map(|utxo| utxo.outpoint).collect::<AHashSet<_>>();
+                    self.selected.retain(|outpoint| outpoints.contains(outpoint));
+                    self.utxos = utxos;
+                }
+                Err(err) => {
+                    self.action = UtxoAction::Error(err.to_string());
+                }
+            }
+        }
+
+        ui.horizontal(|ui| {
+            ui.label(RichText::new(i18n("UTXO Manager")).size(16.).strong());
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui.add_enabled(!utxo_result.is_pending(), Button::new(ARROWS_CLOCKWISE)).on_hover_text(i18n("Refresh")).clicked() {
+                    self.refresh(account.id());
+                }
+            });
+        });
+
+        ui.horizontal(|ui| {
+            ui.label(i18n_args("Selected: {count} of {total} UTXOs", &[
+                ("count", self.selected.len().separated_string()),
+                ("total", self.utxos.len().separated_string()),
+            ]));
+            ui.separator();
+            ui.label(sompi_to_tondi_string_with_suffix(self.selected_amount(), network_type));
+        });
+
+        ui.horizontal(|ui| {
+            if ui.button(i18n("Select All")).clicked() {
+                self.selected = self.utxos.iter()
+                    .filter(|utxo| utxo.is_mature(params, *current_daa_score))
+                    .map(|utxo| utxo.outpoint)
+                    .collect();
+            }
+            if ui.button(i18n("Clear")).clicked() {
+                self.selected.clear();
+            }
+            ui.separator();
+            let enabled = !self.selected.is_empty()
+                && !self.consolidation.is_open()
+                && matches!(self.action, UtxoAction::None | UtxoAction::Sent(_) | UtxoAction::Error(_));
+            if ui.add_enabled(enabled, Button::new(format!("{PAPER_PLANE_TILT} {}", i18n("Send Selected")))).clicked() {
+                self.action = UtxoAction::Send;
+            }
+            ui.separator();
+            let enabled = !self.consolidation.is_open() && !matches!(self.action, UtxoAction::Send | UtxoAction::Processing);
+            if ui.add_enabled(enabled, Button::new(i18n("Consolidate Dust"))).clicked() {
+                self.consolidation.open(ConsolidationKind::Dust);
+            }
+            let split = if self.selected.len() == 1 { self.selected.iter().next().cloned() } else { None };
+            if ui.add_enabled(enabled && split.is_some(), Button::new(i18n("Split UTXO")))
+                .on_disabled_hover_text(i18n("Select a single UTXO to split"))
+                .clicked() {
+                if let Some(outpoint) = split {
+                    self.consolidation.open(ConsolidationKind::Split(outpoint));
+                }
+            }
+        });
+
+        self.render_action(core, ui, rc);
+
+        if self.consolidation.is_open() && self.consolidation.render(core, ui, rc, &self.utxos) {
+            self.selected.clear();
+            self.refresh(account.id());
+        }
+
+        ui.separator();
+
+        if utxo_result.is_pending() && self.utxos.is_empty() {
+            ui.add_space(32.);
+            ui.vertical_centered(|ui| {
+                ui.add(egui::Spinner::new().size(48.));
+            });
+        } else if self.utxos.is_empty() {
+            ui.label(i18n("No UTXOs found"));
+        } else {
+            self.render_utxos(core, ui, rc);
+        }
+    }
+
+    fn render_utxos(&mut self, core: &mut Core, ui : &mut Ui, rc : &RenderContext) {
+        let RenderContext { network_type, current_daa_score, .. } = rc;
+
+        let params: &'static NetworkParams = core.network().into();
+
+        egui::ScrollArea::both().auto_shrink([false,false]).show(ui, |ui| {
+            egui::Grid::new("utxo_manager_grid")
+                .num_columns(6)
+                .striped(true)
+                .spacing([16.,4.])
+                .show(ui, |ui| {
+                    ui.label("");
+                    self.render_header(ui, i18n("Amount"), UtxoSort::Amount);
+                    self.render_header(ui, i18n("DAA Score"), UtxoSort::DaaScore);
+                    ui.label(RichText::new(i18n("Maturity")).strong());
+                    self.render_header(ui, i18n("Address"), UtxoSort::Address);
+                    self.render_header(ui, i18n("Coinbase"), UtxoSort::Coinbase);
+                    ui.end_row();
+
+                    for utxo in self.utxos.iter() {
+                        // only mature UTXOs can fund a transaction
+                        let is_mature = utxo.is_mature(params, *current_daa_score);
+                        let mut checked = self.selected.contains(&utxo.outpoint);
+                        if ui.add_enabled(is_mature, egui::Checkbox::without_text(&mut checked))
+                            .on_disabled_hover_text(i18n("This UTXO is not mature yet"))
+                            .changed() {
+                            if checked {
+                                self.selected.insert(utxo.outpoint);
+                            } else {
+                                self.selected.remove(&utxo.outpoint);
+                            }
+                        }
 
-        egui::ScrollArea::vertical().auto_shrink([false,false]).show(ui, |ui| {
-            ui.label(i18n("UTXO Manager"));
+                        ui.label(RichText::new(sompi_to_tondi_string_with_suffix(utxo.amount, network_type)).monospace())
+                            .on_hover_text(format!("{}:{}", utxo.outpoint.0, utxo.outpoint.1));
+                        ui.label(utxo.block_daa_score.separated_string());
 
-            ui.label("");
-            ui.label("UTXO management is not implemented in this beta release.");
-            ui.label("");
+                        match current_daa_score.and_then(|daa_score| utxo.maturity_progress(params, daa_score)) {
+                            Some(progress) => ui.label(format!("{}%", (progress * 100.) as usize)),
+                            None if current_daa_score.is_some() => ui.label(i18n("Mature")),
+                            None => ui.label("-"),
+                        };
 
+                        if let Some(address) = utxo.address.as_ref() {
+                            ui.label(format_address(address, Some(8))).on_hover_text(address.to_string());
+                        } else {
+                            ui.label("-");
+                        }
+
+                        ui.label(if utxo.is_coinbase { i18n("Yes") } else { i18n("No") });
+                        ui.end_row();
+                    }
+                });
         });
+    }
+
+    fn render_header(&mut self, ui : &mut Ui, caption : &str, sort : UtxoSort) {
+        let text = if self.sort == sort {
+            format!("{caption} {}", if self.ascending { CARET_UP } else { CARET_DOWN })
+        } else {
+            caption.to_string()
+        };
+
+        if ui.add(Label::new(RichText::new(text).strong()).sense(Sense::click())).clicked() {
+            if self.sort == sort {
+                self.ascending = !self.ascending;
+            } else {
+                self.sort = sort;
+                self.ascending = false;
+            }
+            self.sort.sort(&mut self.utxos, self.ascending);
+        }
+    }
+
+    fn render_action(&mut self, core: &mut Core, ui : &mut Ui, rc : &RenderContext) {
+        let RenderContext { account, network_type, .. } = rc;
+
+        let send_result = Payload::<Result<GeneratorSummary>>::new("utxo_send_result");
+
+        match self.action.clone() {
+            UtxoAction::None => { }
+            UtxoAction::Send => {
+                ui.add_space(8.);
+
+                ui.label(RichText::new(i18n("Enter destination address")).size(12.).raised());
+                ui.add(TextEdit::singleline(&mut self.destination_address_string).desired_width(f32::INFINITY));
+                let address_status = address_status(self.destination_address_string.as_str(), *network_type);
+                match &address_status {
+                    AddressStatus::NetworkMismatch(address_network_type) => {
+                        ui.label(i18n_args("This address if for the different network ({address_network_type})", &[("address_network_type", address_network_type.to_string())]));
+                    }
+                    AddressStatus::Invalid(err) if self.destination_address_string.is_not_empty() => {
+                        ui.label(i18n_args("Please enter a valid address: {err}", &[("err", err)]));
+                    }
+                    _ => { }
+                }
+
+                ui.label(RichText::new(i18n("Amount (leave empty to send all selected UTXOs)")).size(12.).raised());
+                ui.add(TextEdit::singleline(&mut self.send_amount_text).desired_width(f32::INFINITY));
+                let send_amount_sompi = try_tondi_str_to_sompi(self.send_amount_text.as_str())
+                    .map(|amount| amount.filter(|amount| *amount > 0));
+
+                ui.label(RichText::new(i18n("Priority Fees")).size(12.).raised());
+                ui.add(TextEdit::singleline(&mut self.priority_fees_text).desired_width(f32::INFINITY));
+                let priority_fees_sompi = try_tondi_str_to_sompi(self.priority_fees_text.as_str())
+                    .map(Option::unwrap_or_default);
+
+                ui.label(RichText::new(i18n("Enter wallet password")).size(12.).raised());
+                ui.add(TextEdit::singleline(&mut self.wallet_secret).password(true).desired_width(f32::INFINITY));
+
+                let requires_payment_passphrase = account.requires_bip39_passphrase(core);
+                if requires_payment_passphrase {
+                    ui.label(RichText::new(i18n("Enter bip39 passphrase")).size(12.).raised());
+                    ui.add(TextEdit::singleline(&mut self.payment_secret).password(true).desired_width(f32::INFINITY));
+                }
 
+                let selected_amount = self.selected_amount();
+                let amount_error = match &send_amount_sompi {
+                    Ok(Some(amount)) if *amount > selected_amount => Some(i18n("Amount exceeds the value of the selected UTXOs").to_string()),
+                    Ok(_) => None,
+                    Err(err) => Some(err.to_string()),
+                };
+                if let Some(err) = &amount_error {
+                    ui.label(RichText::new(err).color(theme_color().error_color));
+                }
+
+                let is_ready_to_send = address_status == AddressStatus::Valid
+                    && amount_error.is_none()
+                    && priority_fees_sompi.is_ok()
+                    && !self.wallet_secret.is_empty()
+                    && !(requires_payment_passphrase && self.payment_secret.is_empty());
+
+                ui.add_space(8.);
+                ui.horizontal(|ui| {
+                    if ui.add_enabled(is_ready_to_send, Button::new(format!("{CHECK} {}", i18n("Submit")))).clicked() {
+                        let address = Address::try_from(self.destination_address_string.as_str()).expect("valid address");
+                        let priority_fee_sompi = priority_fees_sompi.unwrap_or_default();
+                        let (destination, priority_fee) = match send_amount_sompi.ok().flatten() {
+                            Some(amount) => (PaymentOutput { address, amount }, Fees::SenderPays(priority_fee_sompi)),
+                            None => (PaymentOutput { address, amount: selected_amount }, Fees::ReceiverPays(priority_fee_sompi)),
+                        };
+
+                        let account_id = account.id();
+                        let outpoints = self.selected.iter().cloned().collect::<Vec<_>>();
+                        let wallet_secret = Secret::from(self.wallet_secret.clone());
+                        let payment_secret = requires_payment_passphrase.then_some(Secret::from(self.payment_secret.clone()));
+                        self.zeroize();
+
+                        spawn_with_result(&send_result, async move {
+                            let generator_summary = send_with_utxos(account_id, outpoints, destination.into(), priority_fee, wallet_secret, payment_secret).await?;
+                            runtime().request_repaint();
+                            Ok(generator_summary)
+                        });
+
+                        self.action = UtxoAction::Processing;
+                    }
+
+                    if ui.button(format!("{X} {}", i18n("Cancel"))).clicked() {
+                        self.zeroize();
+                        self.action = UtxoAction::None;
+                    }
+                });
+            }
+            UtxoAction::Processing => {
+                ui.add_space(8.);
+                ui.add(egui::Spinner::new().size(32.));
+
+                if let Some(result) = send_result.take() {
+                    match result {
+                        Ok(generator_summary) => {
+                            self.selected.clear();
+                            self.action = UtxoAction::Sent(generator_summary);
+                        }
+                        Err(err) => {
+                            self.action = UtxoAction::Error(err.to_string());
+                        }
+                    }
+                    self.refresh(account.id());
+                }
+            }
+            UtxoAction::Sent(generator_summary) => {
+                ui.add_space(8.);
+                ui.label(i18n_args("Transaction submitted: {utxos} UTXOs, fees {fees}", &[
+                    ("utxos", generator_summary.aggregated_utxos.to_string()),
+                    ("fees", sompi_to_tondi_string_with_suffix(generator_summary.aggregated_fees, network_type)),
+                ]));
+                if ui.button(i18n("Close")).clicked() {
+                    self.action = UtxoAction::None;
+                }
+            }
+            UtxoAction::Error(err) => {
+                ui.add_space(8.);
+                ui.label(RichText::new(err).color(theme_color().error_color));
+                if ui.button(i18n("Close")).clicked() {
+                    self.action = UtxoAction::None;
+                }
+            }
+        }
     }
-}
\ No newline at end of file
+}
+
+/// Build generator settings funded exclusively from the given UTXOs. The
+/// public wallet API does not expose input selection, so this requires
+/// the local wallet subsystem.
+async fn generator_settings(
+    account_id : AccountId,
+    outpoints : Vec<Outpoint>,
+    destination : PaymentDestination,
+    priority_fee : Fees,
+) -> Result<(Arc<CoreWallet>, Arc<dyn CoreAccount>, GeneratorSettings)> {
+
+    let wallet = runtime().wallet().downcast_arc::<CoreWallet>().map_err(|_| Error::WalletIsNotLocal)?;
+
+    let account = {
+        let binding = wallet.guard();
+        let guard = binding.lock().await;
+        wallet.get_account_by_id(&account_id, &guard).await?
+            .ok_or_else(|| Error::custom(i18n("Account not found")))?
+    };
+
+    let outpoints = outpoints.into_iter().collect::<AHashSet<_>>();
+    let utxo_entries = account.utxo_context().context().mature.iter()
+        .filter(|utxo| {
+            let outpoint = utxo.outpoint();
+            outpoints.contains(&(outpoint.transaction_id(), outpoint.index()))
+        })
+        .cloned()
+        .collect::<Vec<UtxoEntryReference>>();
+
+    if utxo_entries.len() != outpoints.len() {
+        return Err(Error::custom(i18n("Some of the selected UTXOs are no longer available")));
+    }
+
+    let settings = GeneratorSettings::try_new_with_iterator(
+        wallet.network_id()?,
+        Box::new(utxo_entries.into_iter()),
+        None,
+        account.change_address()?,
+        account.sig_op_count(),
+        account.minimum_signatures(),
+        destination,
+        priority_fee,
+        None,
+        None,
+    )?;
+
+    Ok((wallet, account, settings))
+}
+
+/// Estimate transactions funded exclusively from the given UTXOs.
+pub async fn estimate_with_utxos(
+    account_id : AccountId,
+    outpoints : Vec<Outpoint>,
+    destination : PaymentDestination,
+    priority_fee : Fees,
+) -> Result<GeneratorSummary> {
+
+    let (_, _, settings) = generator_settings(account_id, outpoints, destination, priority_fee).await?;
+    let generator = Generator::try_new(settings, None, None)?;
+
+    let mut stream = generator.stream();
+    while stream.try_next().await?.is_some() { }
+
+    Ok(generator.summary())
+}
+
+/// Create, sign and submit transactions funded exclusively from the given UTXOs.
+pub async fn send_with_utxos(
+    account_id : AccountId,
+    outpoints : Vec<Outpoint>,
+    destination : PaymentDestination,
+    priority_fee : Fees,
+    wallet_secret : Secret,
+    payment_secret : Option<Secret>,
+) -> Result<GeneratorSummary> {
+
+    let (wallet, account, settings) = generator_settings(account_id, outpoints, destination, priority_fee).await?;
+
+    let keydata = account.prv_key_data(wallet_secret).await?;
+    let signer = Arc::new(Signer::new(account.clone(), keydata, payment_secret));
+    let generator = Generator::try_new(settings, Some(signer), None)?;
+
+    let rpc = wallet.rpc_api();
+    let mut stream = generator.stream();
+    while let Some(transaction) = stream.try_next().await? {
+        transaction.try_sign()?;
+        transaction.try_submit(&rpc).await?;
+    }
+
+    Ok(generator.summary())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utxo(index : u32, amount : u64, block_daa_score : u64, is_coinbase : bool) -> UtxoRecord {
+        UtxoRecord {
+            outpoint: (TransactionId::default(), index),
+            address: None,
+            amount,
+            block_daa_score,
+            is_coinbase,
+        }
+    }
+
+    #[test]
+    fn test_utxo_sort() {
+        let mut utxos = vec![utxo(0, 20, 5, false), utxo(1, 10, 7, true), utxo(2, 30, 3, false)];
+
+        UtxoSort::Amount.sort(&mut utxos, true);
+        assert_eq!(utxos.iter().map(|utxo| utxo.outpoint.1).collect::<Vec<_>>(), vec![1, 0, 2]);
+
+        UtxoSort::DaaScore.sort(&mut utxos, false);
+        assert_eq!(utxos.iter().map(|utxo| utxo.outpoint.1).collect::<Vec<_>>(), vec![1, 0, 2]);
+
+        UtxoSort::Coinbase.sort(&mut utxos, false);
+        assert_eq!(utxos[0].outpoint.1, 1);
+    }
+}
@@ -0,0 +1,321 @@
+use crate::imports::*;
+use tondi_rpc_core::RpcBlock;
+
+/// Number of most recent virtual chain blocks listed in the explorer.
+const VIRTUAL_CHAIN_DEPTH: usize = 64;
+
+#[derive(Clone)]
+enum ExplorerView {
+    Block(Arc<RpcBlock>),
+    VirtualChain {
+        start: TondiHash,
+        added: Vec<TondiHash>,
+        removed: Vec<TondiHash>,
+    },
+}
+
+fn rpc_api() -> Result<Arc<DynRpcApi>> {
+    let wallet = runtime()
+        .tondi_service()
+        .core_wallet()
+        .ok_or_else(|| Error::custom("Wallet is not available"))?;
+    if !wallet.has_rpc() {
+        return Err(Error::custom("Not connected"));
+    }
+    Ok(wallet.rpc_api().clone())
+}
+
+async fn fetch_block(hash: Option<TondiHash>) -> Result<ExplorerView> {
+    let rpc_api = rpc_api()?;
+    let hash = match hash {
+        Some(hash) => hash,
+        None => rpc_api.get_sink().await?.sink,
+    };
+    let block = rpc_api.get_block(hash, true).await?;
+    Ok(ExplorerView::Block(Arc::new(block)))
+}
+
+async fn fetch_virtual_chain(start: TondiHash) -> Result<ExplorerView> {
+    let rpc_api = rpc_api()?;
+    let response = rpc_api.get_virtual_chain_from_block(start, false).await?;
+    let added = response.added_chain_block_hashes.iter().rev().take(VIRTUAL_CHAIN_DEPTH).cloned().collect();
+    let removed = response.removed_chain_block_hashes.to_vec();
+    Ok(ExplorerView::VirtualChain { start, added, removed })
+}
+
+pub struct Explorer {
+    search: String,
+    search_error: Option<String>,
+    view: Option<ExplorerView>,
+}
+
+impl Explorer {
+    pub fn new(_runtime: Runtime) -> Self {
+        Self {
+            search: String::new(),
+            search_error: None,
+            view: None,
+        }
+    }
+
+    fn parse_search(&mut self) -> Option<Option<TondiHash>> {
+        let search = self.search.trim();
+        if search.is_empty() {
+            self.search_error = None;
+            return Some(None);
+        }
+
+        match TondiHash::from_str(search) {
+            Ok(hash) => {
+                self.search_error = None;
+                Some(Some(hash))
+            }
+            Err(err) => {
+                self.search_error = Some(err.to_string());
+                None
+            }
+        }
+    }
+
+    fn open_block(&mut self, hash: Option<TondiHash>) {
+        let result = Payload::<Result<ExplorerView>>::new("explorer_result");
+        if !result.is_pending() {
+            spawn_with_result(&result, fetch_block(hash));
+        }
+    }
+
+    fn open_virtual_chain(&mut self, start: TondiHash) {
+        let result = Payload::<Result<ExplorerView>>::new("explorer_result");
+        if !result.is_pending() {
+            spawn_with_result(&result, fetch_virtual_chain(start));
+        }
+    }
+
+    fn render_hash(ui: &mut egui::Ui, hash: &TondiHash, selected: &mut Option<TondiHash>) {
+        let text = hash.to_string();
+        if ui
+            .link(RichText::new(format_partial_string(text.as_str(), Some(16))).color(theme_color().node_data_color))
+            .on_hover_text(text)
+            .clicked()
+        {
+            selected.replace(*hash);
+        }
+    }
+
+    fn render_block(&self, ui: &mut egui::Ui, block: &RpcBlock) -> Option<TondiHash> {
+        let mut selected = None;
+        let header = &block.header;
+
+        Grid::new("explorer_block_grid")
+            .num_columns(2)
+            .spacing([16.0,4.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label(i18n("Hash"));
+                ui.label(RichText::new(header.hash.to_string()).color(theme_color().node_data_color));
+                ui.end_row();
+
+                ui.label(i18n("Timestamp"));
+                let timestamp = chrono::DateTime::from_timestamp_millis(header.timestamp as i64)
+                    .map(|time| time.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M:%S").to_string())
+                    .unwrap_or_else(|| header.timestamp.to_string());
+                ui.label(timestamp);
+                ui.end_row();
+
+                ui.label(i18n("DAA Score"));
+                ui.label(header.daa_score.separated_string());
+                ui.end_row();
+
+                ui.label(i18n("Blue Score"));
+                ui.label(header.blue_score.separated_string());
+                ui.end_row();
+
+                ui.label(i18n("Version"));
+                ui.label(header.version.to_string());
+                ui.end_row();
+
+                ui.label(i18n("Transactions"));
+                ui.label(block.transactions.len().separated_string());
+                ui.end_row();
+
+                if let Some(verbose_data) = block.verbose_data.as_ref() {
+                    ui.label(i18n("Chain Block"));
+                    ui.label(if verbose_data.is_chain_block { i18n("Yes") } else { i18n("No") });
+                    ui.end_row();
+
+                    ui.label(i18n("Difficulty"));
+                    ui.label(format!("{:.2}", verbose_data.difficulty));
+                    ui.end_row();
+
+                    ui.label(i18n("Selected Parent"));
+                    Self::render_hash(ui, &verbose_data.selected_parent_hash, &mut selected);
+                    ui.end_row();
+                }
+            });
+
+        ui.add_space(8.);
+
+        CollapsingHeader::new(i18n("Parents"))
+            .default_open(true)
+            .show(ui, |ui| {
+                for hash in header.direct_parents() {
+                    Self::render_hash(ui, hash, &mut selected);
+                }
+            });
+
+        if let Some(verbose_data) = block.verbose_data.as_ref() {
+            CollapsingHeader::new(i18n("Children"))
+                .default_open(true)
+                .show(ui, |ui| {
+                    if verbose_data.children_hashes.is_empty() {
+                        ui.label(i18n("None"));
+                    }
+                    for hash in verbose_data.children_hashes.iter() {
+                        Self::render_hash(ui, hash, &mut selected);
+                    }
+                });
+
+            CollapsingHeader::new(i18n("Transaction IDs"))
+                .default_open(false)
+                .show(ui, |ui| {
+                    for id in verbose_data.transaction_ids.iter() {
+                        ui.label(RichText::new(id.to_string()).color(theme_color().node_data_color));
+                    }
+                });
+        }
+
+        selected
+    }
+
+    fn render_virtual_chain(&self, ui: &mut egui::Ui, start: &TondiHash, added: &[TondiHash], removed: &[TondiHash]) -> Option<TondiHash> {
+        let mut selected = None;
+
+        ui.horizontal(|ui| {
+            ui.label(i18n("Virtual chain from"));
+            Self::render_hash(ui, start, &mut selected);
+        });
+        ui.add_space(4.);
+
+        if !removed.is_empty() {
+            CollapsingHeader::new(i18n_args("Removed chain blocks ({count})", &[("count", &removed.len().to_string())]))
+                .default_open(false)
+                .show(ui, |ui| {
+                    for hash in removed.iter() {
+                        Self::render_hash(ui, hash, &mut selected);
+                    }
+                });
+        }
+
+        CollapsingHeader::new(i18n_args("Most recent chain blocks ({count})", &[("count", &added.len().to_string())]))
+            .default_open(true)
+            .show(ui, |ui| {
+                if added.is_empty() {
+                    ui.label(i18n("None"));
+                }
+                for hash in added.iter() {
+                    Self::render_hash(ui, hash, &mut selected);
+                }
+            });
+
+        selected
+    }
+}
+
+impl ModuleT for Explorer {
+
+    fn style(&self) -> ModuleStyle {
+        ModuleStyle::Default
+    }
+
+    fn render(
+        &mut self,
+        core: &mut Core,
+        _ctx: &egui::Context,
+        _frame: &mut eframe::Frame,
+        ui: &mut egui::Ui,
+    ) {
+
+        ui.heading(i18n("Block Explorer"));
+        ui.separator();
+
+        if !core.state().is_connected() {
+            ui.label(i18n("Not connected"));
+            return;
+        }
+
+        let result = Payload::<Result<ExplorerView>>::new("explorer_result");
+        if let Some(result) = result.take() {
+            match result {
+                Ok(view) => self.view = Some(view),
+                Err(err) => runtime().error(err.to_string()),
+            }
+        }
+
+        ui.horizontal(|ui| {
+            ui.label(i18n("Block hash:"));
+            ui.add(
+                TextEdit::singleline(&mut self.search)
+                    .hint_text(i18n("Leave empty for the sink block..."))
+                    .desired_width(ui.available_width() * 0.5),
+            );
+            if ui.button(i18n("Block")).clicked() {
+                if let Some(hash) = self.parse_search() {
+                    self.open_block(hash);
+                }
+            }
+            if ui.button(i18n("Virtual Chain")).clicked() {
+                // without an explicit hash, start from the displayed block
+                let start = self.parse_search().map(|hash| {
+                    hash.or_else(|| match self.view.as_ref() {
+                        Some(ExplorerView::Block(block)) => Some(block.header.hash),
+                        _ => None,
+                    })
+                });
+                match start {
+                    Some(Some(start)) => self.open_virtual_chain(start),
+                    Some(None) => self.search_error = Some(i18n("Please enter a starting block hash").to_string()),
+                    None => {}
+                }
+            }
+            if ui.button(i18n("Sink")).clicked() {
+                self.search.clear();
+                self.search_error = None;
+                self.open_block(None);
+            }
+            if result.is_pending() {
+                ui.spinner();
+            }
+        });
+
+        if let Some(error) = self.search_error.as_ref() {
+            ui.colored_label(theme_color().error_color, error);
+        }
+
+        ui.separator();
+
+        let Some(view) = self.view.clone() else {
+            ui.label(i18n("Enter a block hash or select the sink block to start exploring"));
+            return;
+        };
+
+        let selected = egui::ScrollArea::vertical()
+            .auto_shrink([false; 2])
+            .show(ui, |ui| match &view {
+                ExplorerView::Block(block) => self.render_block(ui, block),
+                ExplorerView::VirtualChain { start, added, removed } => self.render_virtual_chain(ui, start, added, removed),
+            })
+            .inner;
+
+        if let Some(hash) = selected {
+            self.search = hash.to_string();
+            self.open_block(Some(hash));
+        }
+    }
+
+    fn activate(&mut self, _core: &mut Core) {
+        if self.view.is_none() {
+            self.open_block(None);
+        }
+    }
+
+}
@@ -1,16 +1,101 @@
 use crate::imports::*;
+use crate::runtime::services::tondi::logs::{Log, LogLevel};
 
 pub struct Logs {
     #[allow(dead_code)]
     runtime: Runtime,
+    hidden_levels: AHashSet<LogLevel>,
+    target: Option<String>,
+    search: String,
+    paused: bool,
 }
 
 impl Logs {
     pub fn new(runtime: Runtime) -> Self {
         Self {
             runtime,
+            hidden_levels: AHashSet::default(),
+            target: None,
+            search: String::new(),
+            paused: false,
         }
     }
+
+    fn is_visible(&self, log: &Log) -> bool {
+        !self.hidden_levels.contains(&log.level)
+            && self.target.as_ref().is_none_or(|target| log.target.as_ref() == Some(target))
+            && (self.search.is_empty() || log.contains(&self.search))
+    }
+
+    fn render_toolbar(&mut self, ui: &mut Ui) {
+        use egui_phosphor::light::{FLOPPY_DISK, PAUSE, PLAY, RECORD, STOP};
+
+        ui.horizontal_wrapped(|ui| {
+            for level in LogLevel::iter() {
+                let mut visible = !self.hidden_levels.contains(level);
+                if ui.toggle_value(&mut visible, level.to_string()).changed() {
+                    if visible {
+                        self.hidden_levels.remove(level);
+                    } else {
+                        self.hidden_levels.insert(*level);
+                    }
+                }
+            }
+
+            ui.separator();
+
+            let mut targets = self.runtime.tondi_service().logs().iter().filter_map(|log| log.target.clone()).collect::<Vec<_>>();
+            targets.sort();
+            targets.dedup();
+
+            egui::ComboBox::from_id_salt("logs_target_filter")
+                .selected_text(self.target.clone().unwrap_or_else(|| i18n("All targets").to_string()))
+                .width(200.)
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut self.target, None, i18n("All targets"));
+                    for target in targets {
+                        ui.selectable_value(&mut self.target, Some(target.clone()), target);
+                    }
+                });
+
+            ui.add(TextEdit::singleline(&mut self.search).hint_text(i18n("Search...")).desired_width(160.));
+
+            ui.separator();
+
+            let (icon, text) = if self.paused { (PLAY, i18n("Resume")) } else { (PAUSE, i18n("Pause")) };
+            if ui.button(format!("{icon} {text}")).on_hover_text(i18n("Pause auto-scroll")).clicked() {
+                self.paused = !self.paused;
+            }
+
+            if ui.button(format!("{FLOPPY_DISK} {}", i18n("Save"))).on_hover_text(i18n("Save the log buffer to a file")).clicked() {
+                if let Some(path) = Self::select_file() {
+                    match self.runtime.tondi_service().save_logs(&path) {
+                        Ok(()) => runtime().notify(UserNotification::success(i18n_args("Logs saved to {path}", &[("path", path.to_string_lossy())])).as_toast()),
+                        Err(err) => runtime().error(format!("{}: {err}", i18n("Unable to save logs"))),
+                    }
+                }
+            }
+
+            if let Some(path) = self.runtime.tondi_service().log_tail() {
+                if ui.button(format!("{STOP} {}", i18n("Stop Live Tail"))).on_hover_text(path.to_string_lossy()).clicked() {
+                    self.runtime.tondi_service().stop_log_tail();
+                }
+            } else if ui.button(format!("{RECORD} {}", i18n("Live Tail"))).on_hover_text(i18n("Save the log buffer and keep appending new lines to the file")).clicked() {
+                if let Some(path) = Self::select_file() {
+                    if let Err(err) = self.runtime.tondi_service().start_log_tail(path) {
+                        runtime().error(format!("{}: {err}", i18n("Unable to save logs")));
+                    }
+                }
+            }
+        });
+    }
+
+    fn select_file() -> Option<std::path::PathBuf> {
+        rfd::FileDialog::new()
+            .add_filter("Log", &["log", "txt"])
+            .set_file_name("tondid.log")
+            .save_file()
+    }
 }
 
 impl ModuleT for Logs {
@@ -27,16 +112,21 @@ impl ModuleT for Logs {
         let available_width = ui.available_width();
 
         #[cfg(not(target_arch = "wasm32"))]
-        egui::ScrollArea::vertical()
-            .id_salt("node_logs")
-            .auto_shrink([false; 2])
-            .stick_to_bottom(true)
-            .show(ui, |ui| {
-
-                for log in self.runtime.tondi_service().logs().iter() {
-                    ui.label(RichText::from(log));
-                }
-            });
+        {
+            self.render_toolbar(ui);
+            ui.separator();
+
+            egui::ScrollArea::vertical()
+                .id_salt("node_logs")
+                .auto_shrink([false; 2])
+                .stick_to_bottom(!self.paused)
+                .show(ui, |ui| {
+
+                    for log in self.runtime.tondi_service().logs().iter().filter(|log| self.is_visible(log)) {
+                        ui.label(RichText::from(log));
+                    }
+                });
+        }
 
         let copy_to_clipboard = Button::new(RichText::new(format!(" {CLIPBOARD_TEXT} ")).size(20.));
 
@@ -0,0 +1,164 @@
+use crate::imports::*;
+use crate::runtime::services::mempool_monitor::MempoolEntry;
+
+pub struct Mempool {
+    runtime: Runtime,
+    address_filter: String,
+    address_filter_error: Option<String>,
+    include_orphans: bool,
+}
+
+impl Mempool {
+    pub fn new(runtime: Runtime) -> Self {
+        Self {
+            runtime,
+            address_filter: String::new(),
+            address_filter_error: None,
+            include_orphans: true,
+        }
+    }
+
+    fn apply_address_filter(&mut self) {
+        let addresses = self
+            .address_filter
+            .split([',', ' ', '\n'])
+            .filter(|address| !address.is_empty())
+            .map(Address::try_from)
+            .collect::<std::result::Result<Vec<_>, _>>();
+
+        match addresses {
+            Ok(addresses) => {
+                self.address_filter_error = None;
+                let filter = (!addresses.is_empty()).then_some(addresses);
+                self.runtime.mempool_monitor_service().set_filter(filter);
+            }
+            Err(err) => {
+                self.address_filter_error = Some(err.to_string());
+            }
+        }
+    }
+}
+
+impl ModuleT for Mempool {
+
+    fn style(&self) -> ModuleStyle {
+        ModuleStyle::Default
+    }
+
+    fn render(
+        &mut self,
+        core: &mut Core,
+        _ctx: &egui::Context,
+        _frame: &mut eframe::Frame,
+        ui: &mut egui::Ui,
+    ) {
+
+        ui.heading(i18n("Mempool"));
+        ui.separator();
+
+        if !core.state().is_connected() {
+            ui.label(i18n("Not connected"));
+            return;
+        }
+
+        ui.horizontal(|ui| {
+            ui.label(i18n("Address:"));
+            let response = ui.add(
+                TextEdit::singleline(&mut self.address_filter)
+                    .hint_text(i18n("Filter by address..."))
+                    .desired_width(ui.available_width() * 0.6),
+            );
+            if response.lost_focus() || ui.button(i18n("Apply")).clicked() {
+                self.apply_address_filter();
+            }
+            if ui.button(i18n("Clear")).clicked() {
+                self.address_filter.clear();
+                self.apply_address_filter();
+            }
+            ui.checkbox(&mut self.include_orphans, i18n("Orphans"));
+        });
+
+        if let Some(error) = self.address_filter_error.as_ref() {
+            ui.colored_label(theme_color().error_color, error);
+        }
+
+        ui.separator();
+
+        let Some(entries) = self.runtime.mempool_monitor_service().entries() else {
+            ui.horizontal(|ui| {
+                ui.spinner();
+                ui.label(i18n("Updating..."));
+            });
+            return;
+        };
+
+        let mut entries = entries
+            .iter()
+            .filter(|entry| self.include_orphans || !entry.is_orphan)
+            .collect::<Vec<&MempoolEntry>>();
+        entries.sort_by(|a, b| b.fee_rate().total_cmp(&a.fee_rate()));
+
+        let orphans = entries.iter().filter(|entry| entry.is_orphan).count();
+        ui.label(i18n_args(
+            "Pending transactions: {count} (orphans: {orphans})",
+            &[
+                ("count", &entries.len().to_string()),
+                ("orphans", &orphans.to_string()),
+            ],
+        ));
+        ui.add_space(4.);
+
+        if entries.is_empty() {
+            ui.label(i18n("No pending transactions"));
+            return;
+        }
+
+        let network_type = NetworkType::from(core.settings.node.network);
+        let color = theme_color().node_data_color;
+
+        egui::ScrollArea::vertical()
+            .auto_shrink([false; 2])
+            .show(ui, |ui| {
+
+                Grid::new("mempool_entries_grid")
+                    .num_columns(6)
+                    .spacing([16.0,4.0])
+                    .striped(true)
+                    .show(ui, |ui| {
+
+                        ui.strong(i18n("Transaction ID"));
+                        ui.strong(i18n("Fee"));
+                        ui.strong(i18n("Fee Rate"));
+                        ui.strong(i18n("Size"));
+                        ui.strong(i18n("Mass"));
+                        ui.strong(i18n("Orphan"));
+                        ui.end_row();
+
+                        for entry in entries.iter() {
+                            let id = entry.id.map(|id| id.to_string()).unwrap_or_else(|| "-".to_string());
+                            ui.colored_label(color, format_partial_string(id.as_str(), Some(12)))
+                                .on_hover_text(id);
+                            ui.label(sompi_to_tondi_string_with_suffix(entry.fee, &network_type));
+                            ui.label(format!("{:.2}", entry.fee_rate()));
+                            ui.label(entry.size.map(|size| format!("{} B", size.separated_string())).unwrap_or_else(|| "-".to_string()));
+                            ui.label(entry.mass.separated_string());
+                            if entry.is_orphan {
+                                ui.colored_label(theme_color().warning_color, i18n("Yes"));
+                            } else {
+                                ui.label(i18n("No"));
+                            }
+                            ui.end_row();
+                        }
+                    });
+            });
+    }
+
+    fn activate(&mut self, _core: &mut Core) {
+        self.runtime.mempool_monitor_service().enable();
+    }
+
+    fn deactivate(&mut self, _core: &mut Core) {
+        self.runtime.mempool_monitor_service().disable();
+    }
+
+}
@@ -1,5 +1,5 @@
 use crate::imports::*;
-use crate::runtime::services::metrics_monitor::MAX_METRICS_SAMPLES;
+use crate::runtime::services::metrics_monitor::{export_metrics, MetricsExportFormat, MAX_METRICS_HISTORY_RANGE};
 use egui_extras::{StripBuilder, Size};
 use tondi_metrics_core::{Metric,MetricGroup, MetricsSnapshot};
 use chrono::DateTime;
@@ -11,7 +11,7 @@ use egui_plot::{
     PlotPoints, uniform_grid_spacer, CoordinatesFormatter, Corner,
 };
 
-const METRICS_SAMPLES_START : isize = -(MAX_METRICS_SAMPLES as isize);
+const METRICS_SAMPLES_START : isize = -(MAX_METRICS_HISTORY_RANGE as isize);
 const MIN_RANGE : isize = 15;
 
 pub struct Metrics {
@@ -137,6 +137,28 @@ impl ModuleT for Metrics {
 
                 ui.separator();
 
+                #[cfg(not(target_arch = "wasm32"))]
+                {
+                    PopupPanel::new(PopupPanel::id(ui,"metrics_export"),|ui|{ ui.add(Label::new("Export ⏷").sense(Sense::click())) }, |ui, close| {
+                        ui.label(i18n("Export visible metrics over the selected range"));
+                        ui.space();
+                        ui.horizontal(|ui| {
+                            for format in MetricsExportFormat::iter() {
+                                if ui.button(format.describe()).clicked() {
+                                    self.export(core, format);
+                                    *close = true;
+                                }
+                            }
+                        });
+                    })
+                    .with_min_width(240.)
+                    .with_caption(i18n("Export"))
+                    .with_close_button(true)
+                    .build(ui);
+
+                    ui.separator();
+                }
+
                 ui.add(
                     Slider::new(&mut graph_range_to, (METRICS_SAMPLES_START+MIN_RANGE)..=0)
                         .logarithmic(true)
@@ -255,6 +277,37 @@ impl ModuleT for Metrics {
 
 impl Metrics {
 
+    #[cfg(not(target_arch = "wasm32"))]
+    fn export(&self, core: &Core, format: MetricsExportFormat) {
+        let metrics = &core.settings.user_interface.metrics;
+        let range = metrics.graph_range_from..metrics.graph_range_to;
+        let series = Metric::iter()
+            .filter(|metric| !metrics.disabled.contains(metric))
+            .map(|metric| (*metric, self.runtime.metrics_service().metrics_range(*metric, range.clone())))
+            .collect::<Vec<_>>();
+
+        let Some(mut path) = rfd::FileDialog::new()
+            .add_filter(format.describe(), &[format.extension()])
+            .set_file_name(format!("tondi-metrics-{}.{}", core.settings.node.network, format.extension()))
+            .save_file()
+        else {
+            return;
+        };
+        path.set_extension(format.extension());
+
+        match export_metrics(&series, format).and_then(|data| Ok(std::fs::write(&path, data)?)) {
+            Ok(()) => {
+                runtime().notify(UserNotification::success(i18n_args(
+                    "Metrics exported to {path}",
+                    &[("path", path.to_string_lossy())],
+                )).as_toast());
+            }
+            Err(err) => {
+                runtime().error(format!("{}: {err}", i18n("Unable to export metrics")));
+            }
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn render_metric(
         &mut self, 
@@ -284,17 +337,7 @@ impl Metrics {
 
                         // ---
                         let graph_data = {
-                            let metrics_data = self.runtime.metrics_service().metrics_data();
-                            let data = metrics_data.get(&metric).unwrap();
-                            let mut start = range.start.clamp(METRICS_SAMPLES_START, 0).unsigned_abs();
-                            let mut end = range.end.clamp(METRICS_SAMPLES_START, 0).unsigned_abs();
-                            if start > data.len() {
-                                start = data.len();
-                            }
-                            if end > data.len() {
-                                end = data.len();
-                            }
-                            let data = data[data.len()-start..data.len()-end].to_vec();
+                            let data = self.runtime.metrics_service().metrics_range(metric, range.clone());
                             
                             // 为详细图表添加平滑插值，使波形更美观
                             if data.len() > 1 {
@@ -334,12 +377,13 @@ impl Metrics {
                                     metric => metric.format(grid.value, true, true)
                                 }
                             })
-                            .x_axis_formatter(move |grid, _range| {
+                            .x_axis_formatter(move |grid, range| {
                                 // 时间戳已经是毫秒，直接转换为秒
+                                let format = if range.end() - range.start() > 24. * 60. * 60. * 1000. { "%m-%d %H:%M" } else { "%H:%M:%S" };
                                 DateTime::<chrono::Utc>::from_timestamp((grid.value / 1000.0) as i64, 0)
                                     .expect("could not parse timestamp")
                                     .with_timezone(&chrono::Local)
-                                    .format("%H:%M:%S")
+                                    .format(format)
                                     .to_string()
                             })
                             .x_grid_spacer(
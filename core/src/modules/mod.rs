@@ -27,7 +27,7 @@ tondi_dashboard_macros::register_modules!(
 tondi_dashboard_macros::register_modules!(register_native_modules, [changelog, logs, node,]);
 
 #[cfg(not(feature = "lean"))]
-tondi_dashboard_macros::register_modules!(register_advanced_modules, [block_dag, metrics,]);
+tondi_dashboard_macros::register_modules!(register_advanced_modules, [block_dag, explorer, mempool, metrics,]);
 
 pub enum ModuleStyle {
     Mobile,
@@ -1,16 +1,185 @@
+use std::net::IpAddr;
 use tondi_rpc_core::RpcPeerInfo;
 
 use crate::imports::*;
 use crate::utils::format_duration;
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum NodeTab {
+    Peers,
+    Addresses,
+}
+
 pub struct Node {
-    #[allow(dead_code)]
     runtime: Runtime,
+    tab: NodeTab,
+    peer_address: String,
+    peer_is_permanent: bool,
+    ban_ip: String,
+    input_error: Option<String>,
 }
 
 impl Node {
     pub fn new(runtime: Runtime) -> Self {
-        Self { runtime }
+        Self {
+            runtime,
+            tab: NodeTab::Peers,
+            peer_address: String::new(),
+            peer_is_permanent: false,
+            ban_ip: String::new(),
+            input_error: None,
+        }
+    }
+
+    fn render_add_peer(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label(i18n("Peer address:"));
+            ui.add(TextEdit::singleline(&mut self.peer_address).hint_text("ip:port").desired_width(220.));
+            ui.checkbox(&mut self.peer_is_permanent, i18n("Permanent"));
+            if ui.button(i18n("Add Peer")).clicked() {
+                match ContextualNetAddress::from_str(self.peer_address.trim()) {
+                    Ok(address) => {
+                        self.input_error = None;
+                        self.runtime.peer_monitor_service().add_peer(address, self.peer_is_permanent);
+                        self.peer_address.clear();
+                    }
+                    Err(err) => {
+                        self.input_error = Some(i18n_args("Invalid peer address: {error}", &[("error", &err.to_string())]));
+                    }
+                }
+            }
+        });
+    }
+
+    fn render_ban(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label(i18n("IP address:"));
+            ui.add(TextEdit::singleline(&mut self.ban_ip).desired_width(220.));
+            let ip = IpAddr::from_str(self.ban_ip.trim());
+            if ui.button(i18n("Ban")).clicked() {
+                match ip {
+                    Ok(ip) => {
+                        self.input_error = None;
+                        self.runtime.peer_monitor_service().ban(ip);
+                        self.ban_ip.clear();
+                    }
+                    Err(err) => {
+                        self.input_error = Some(i18n_args("Invalid IP address: {error}", &[("error", &err.to_string())]));
+                    }
+                }
+            } else if ui.button(i18n("Unban")).clicked() {
+                match ip {
+                    Ok(ip) => {
+                        self.input_error = None;
+                        self.runtime.peer_monitor_service().unban(ip);
+                        self.ban_ip.clear();
+                    }
+                    Err(err) => {
+                        self.input_error = Some(i18n_args("Invalid IP address: {error}", &[("error", &err.to_string())]));
+                    }
+                }
+            }
+        });
+    }
+
+    fn render_peers(&mut self, core: &mut Core, ui: &mut Ui) {
+
+        self.render_add_peer(ui);
+        ui.add_space(4.);
+
+        CollapsingHeader::new(i18n("Network Peers"))
+            .default_open(true)
+            .show(ui, |ui| {
+
+                ui.vertical(|ui| {
+
+                    if let Some(peers) = self.runtime.peer_monitor_service().peer_info() {
+                        let (outbound, inbound) : (Vec<_>,Vec<_>) = peers.iter().partition(|peer| peer.is_outbound);
+
+                        CollapsingHeader::new(i18n("Inbound"))
+                            .default_open(true)
+                            .show(ui, |ui| {
+
+                                inbound.iter().for_each(|peer| {
+                                    if render_peer(ui, peer) {
+                                        self.runtime.peer_monitor_service().ban(peer.address.ip.into());
+                                    }
+                                });
+                            });
+
+                        CollapsingHeader::new(i18n("Outbound"))
+                            .default_open(true)
+                            .show(ui, |ui| {
+
+                                outbound.iter().for_each(|peer| {
+                                    if render_peer(ui, peer) {
+                                        self.runtime.peer_monitor_service().ban(peer.address.ip.into());
+                                    }
+                                });
+                            });
+                    } else {
+                        let metrics = core.state().metrics();
+                        let active_peers = metrics.as_ref().map(|m| m.data.node_active_peers).unwrap_or_default();
+
+                        if active_peers > 0 {
+                            ui.horizontal(|ui| {
+                                ui.spinner();
+                                ui.label(i18n("Updating..."));
+                            });
+                        } else {
+                            ui.colored_label(theme_color().warning_color, i18n("No peers"));
+                        }
+                    }
+
+                });
+
+            });
+    }
+
+    fn render_addresses(&mut self, ui: &mut Ui) {
+
+        self.render_ban(ui);
+        ui.add_space(4.);
+
+        let Some(peer_addresses) = self.runtime.peer_monitor_service().peer_addresses() else {
+            ui.horizontal(|ui| {
+                ui.spinner();
+                ui.label(i18n("Updating..."));
+            });
+            return;
+        };
+
+        let color = theme_color().node_data_color;
+
+        CollapsingHeader::new(i18n_args("Banned Addresses ({count})", &[("count", &peer_addresses.banned_addresses.len().to_string())]))
+            .default_open(true)
+            .show(ui, |ui| {
+                if peer_addresses.banned_addresses.is_empty() {
+                    ui.label(i18n("No banned addresses"));
+                }
+
+                for ip in peer_addresses.banned_addresses.iter() {
+                    ui.horizontal(|ui| {
+                        ui.colored_label(color, ip.to_string());
+                        if ui.small_button(i18n("Unban")).clicked() {
+                            self.runtime.peer_monitor_service().unban((*ip).into());
+                        }
+                    });
+                }
+            });
+
+        CollapsingHeader::new(i18n_args("Known Addresses ({count})", &[("count", &peer_addresses.known_addresses.len().to_string())]))
+            .default_open(false)
+            .show(ui, |ui| {
+                for address in peer_addresses.known_addresses.iter() {
+                    ui.horizontal(|ui| {
+                        ui.colored_label(color, address.to_string());
+                        if ui.small_button(i18n("Ban")).clicked() {
+                            self.runtime.peer_monitor_service().ban(address.ip.into());
+                        }
+                    });
+                }
+            });
     }
 }
 
@@ -36,64 +205,39 @@ impl ModuleT for Node {
             return;
         }
 
-        egui::ScrollArea::vertical()
-            .auto_shrink([false; 2])
+        ui.horizontal(|ui| {
+            ui.selectable_value(&mut self.tab, NodeTab::Peers, i18n("Peers"));
+            if ui.selectable_value(&mut self.tab, NodeTab::Addresses, i18n("Addresses")).clicked() {
+                self.runtime.peer_monitor_service().fetch_peer_addresses();
+            }
+            if self.tab == NodeTab::Addresses && ui.button(i18n("Refresh")).clicked() {
+                self.runtime.peer_monitor_service().fetch_peer_addresses();
+            }
+        });
 
-            .show(ui, |ui| {
+        if let Some(error) = self.input_error.as_ref() {
+            ui.colored_label(theme_color().error_color, error);
+        }
 
+        ui.separator();
 
-                CollapsingHeader::new(i18n("Network Peers"))
-                    .default_open(true)
-                    .show(ui, |ui| {
-
-                        ui.vertical(|ui| {
-
-                            if let Some(peers) = self.runtime.peer_monitor_service().peer_info() {
-                                println!("[Node] Rendering {} peers", peers.len());
-                                let (outbound, inbound) : (Vec<_>,Vec<_>) = peers.iter().partition(|peer| peer.is_outbound);
-                                println!("[Node] Inbound: {}, Outbound: {}", inbound.len(), outbound.len());
-
-                                CollapsingHeader::new(i18n("Inbound"))
-                                    .default_open(true)
-                                    .show(ui, |ui| {
-
-                                        inbound.iter().for_each(|peer| {
-                                            render_peer(ui, peer);
-                                        });
-                                    });
-
-                                CollapsingHeader::new(i18n("Outbound"))
-                                    .default_open(true)
-                                    .show(ui, |ui| {
-
-                                        outbound.iter().for_each(|peer| {
-                                            render_peer(ui, peer);
-                                        });
-                                    });
-                            } else {
-                                let metrics = core.state().metrics();
-                                let active_peers = metrics.as_ref().map(|m| m.data.node_active_peers).unwrap_or_default();
-                                println!("[Node] No peer info available, active_peers from metrics: {}", active_peers);
-                                
-                                if active_peers > 0 {
-                                    ui.horizontal(|ui| {
-                                        ui.spinner();
-                                        ui.label(i18n("Updating..."));
-                                    });
-                                } else {
-                                    ui.colored_label(theme_color().warning_color, i18n("No peers"));
-                                }
-                            }
-
-                        });
+        egui::ScrollArea::vertical()
+            .auto_shrink([false; 2])
 
-                    });
-                });
+            .show(ui, |ui| {
+                match self.tab {
+                    NodeTab::Peers => self.render_peers(core, ui),
+                    NodeTab::Addresses => self.render_addresses(ui),
+                }
+            });
             
     }
 
     fn activate(&mut self, _core: &mut Core) {
         crate::runtime::runtime().peer_monitor_service().enable();
+        if self.tab == NodeTab::Addresses {
+            crate::runtime::runtime().peer_monitor_service().fetch_peer_addresses();
+        }
     }
 
     fn deactivate(&mut self, _core: &mut Core) {
@@ -102,9 +246,11 @@ impl ModuleT for Node {
 
 }
 
-fn render_peer(ui : &mut Ui, peer: &RpcPeerInfo) {
+/// Renders peer information, returns `true` if the user requested the peer to be banned.
+fn render_peer(ui : &mut Ui, peer: &RpcPeerInfo) -> bool {
 
     let color = theme_color().node_data_color;
+    let mut ban = false;
 
     CollapsingHeader::new(peer.id.to_string())
         .default_open(true)
@@ -140,7 +286,15 @@ fn render_peer(ui : &mut Ui, peer: &RpcPeerInfo) {
                         ui.colored_label(color, format_duration(peer.time_connected));
                     });
                     ui.end_row();
+
+                    ui.label(i18n("Actions"));
+                    if ui.small_button(i18n("Ban")).clicked() {
+                        ban = true;
+                    }
+                    ui.end_row();
                 });
 
         });
+
+    ban
 }
\ No newline at end of file
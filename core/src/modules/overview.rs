@@ -4,6 +4,9 @@ use workflow_core::runtime::is_wasm;
 use tondi_metrics_core::{Metric,MetricGroup};
 #[cfg(not(feature = "lean"))]
 use egui_plot::{
+    BoxElem,
+    BoxPlot,
+    BoxSpread,
     Legend,
     Line,
     LineStyle,
@@ -12,15 +15,17 @@ use egui_plot::{
 };
 
 use crate::imports::*;
+use crate::market::*;
 
 pub struct Overview {
     #[allow(dead_code)]
     runtime: Runtime,
+    chart_currency: String,
 }
 
 impl Overview {
     pub fn new(runtime: Runtime) -> Self {
-        Self { runtime }
+        Self { runtime, chart_currency: "usd".to_string() }
     }
 }
 
@@ -156,6 +161,9 @@ impl Overview {
                                         }
                                     })
                                 }
+
+                                #[cfg(not(feature = "lean"))]
+                                self.render_price_chart(core, market, ui);
                             });
                         }
                     }
@@ -381,6 +389,123 @@ impl Overview {
             });
     }
 
+    #[cfg(not(feature = "lean"))]
+    fn render_price_chart(&mut self, core: &Core, market: &Market, ui : &mut Ui) {
+
+        CollapsingHeader::new(i18n("Price Chart"))
+            .default_open(true)
+            .show(ui, |ui| {
+
+                let market_monitor = self.runtime.market_monitor_service();
+                let selected_range = market_monitor.ohlc_range();
+
+                ui.horizontal(|ui| {
+                    for range in OhlcRange::iter() {
+                        if ui.selectable_label(*range == selected_range, range.to_string()).clicked() {
+                            market_monitor.select_ohlc_range(*range);
+                        }
+                    }
+
+                    if let Some(ohlc_map) = market.ohlc.as_ref() {
+                        ui.separator();
+                        let mut currencies = ohlc_map.keys().cloned().collect::<Vec<_>>();
+                        currencies.sort();
+                        for currency in currencies {
+                            let text = currency.to_uppercase();
+                            ui.selectable_value(&mut self.chart_currency, currency, text);
+                        }
+                    }
+                });
+
+                let Some(ohlc) = market.ohlc.as_ref().and_then(|ohlc_map| ohlc_map.get(&self.chart_currency)) else {
+                    ui.label(i18n("Loading price history..."));
+                    return;
+                };
+
+                if ohlc.range != selected_range {
+                    ui.label(i18n("Loading price history..."));
+                }
+
+                if ohlc.candles.len() < 2 {
+                    ui.label(i18n("Price history is not available"));
+                    return;
+                }
+
+                let symbol = self.chart_currency.to_uppercase();
+                let precision = ohlc.precision;
+                let spacing = (ohlc.candles[1].timestamp - ohlc.candles[0].timestamp).abs();
+                let range = ohlc.range;
+
+                let candles = ohlc.candles.iter().map(|candle| {
+                    let color = if candle.close >= candle.open { theme_color().market_up_color } else { theme_color().market_down_color };
+                    let (body_low, body_high) = (candle.open.min(candle.close), candle.open.max(candle.close));
+                    BoxElem::new(candle.timestamp, BoxSpread::new(candle.low, body_low, (body_low + body_high) / 2.0, body_high, candle.high))
+                        .box_width(spacing * 0.7)
+                        .whisker_width(0.0)
+                        .fill(color.linear_multiply(0.5))
+                        .stroke(Stroke::new(1.0, color))
+                }).collect::<Vec<_>>();
+
+                Self::time_plot("market_price_chart", range, precision, symbol.as_str())
+                    .show(ui, |plot_ui| {
+                        plot_ui.box_plot(BoxPlot::new(i18n("Price"), candles));
+                    });
+
+                // value of the current wallet holdings over the candle range
+                let is_priced_network = core.settings.node.network == Network::Mainnet || core.settings.developer.market_monitor_on_testnet;
+                let balance = core.account_collection().as_ref().map(|account_collection| {
+                    account_collection.iter().filter_map(|account| account.balance()).map(|balance| balance.mature).sum::<u64>()
+                });
+
+                if let Some(balance) = balance.filter(|_| is_priced_network) {
+                    let amount = sompi_to_tondi(balance);
+                    let points = ohlc.candles.iter().map(|candle| [candle.timestamp, candle.close * amount]).collect::<Vec<_>>();
+                    if let Some(value) = points.last().map(|point| point[1]) {
+                        ui.add_space(4.);
+                        ui.label(i18n_args("Portfolio Value: {value}", &[("value", format_currency_with_symbol(value, precision, symbol.as_str()))]));
+                    }
+
+                    Self::time_plot("market_portfolio_chart", range, precision, symbol.as_str())
+                        .height(96.)
+                        .show(ui, |plot_ui| {
+                            plot_ui.line(
+                                Line::new(i18n("Portfolio Value"), PlotPoints::from(points))
+                                    .color(theme_color().market_default_color)
+                                    .style(LineStyle::Solid)
+                                    .width(1.5)
+                            );
+                        });
+
+                    ui.label(RichText::new(i18n("Based on the current balance of the open wallet")).size(10.));
+                }
+            });
+    }
+
+    #[cfg(not(feature = "lean"))]
+    fn time_plot(id: &str, range: OhlcRange, precision: usize, symbol: &str) -> Plot<'static> {
+        let x_format = if range == OhlcRange::Day { "%H:%M" } else { "%m-%d" };
+        let symbol = symbol.to_string();
+
+        Plot::new(id.to_string())
+            .height(160.)
+            .allow_drag([false, false])
+            .allow_scroll(false)
+            .allow_zoom([false, false])
+            .show_background(false)
+            .x_axis_formatter(move |grid, _range| {
+                chrono::DateTime::from_timestamp_millis(grid.value as i64)
+                    .map(|time| time.with_timezone(&chrono::Local).format(x_format).to_string())
+                    .unwrap_or_default()
+            })
+            .y_axis_formatter(move |grid, _range| format_currency(grid.value, precision))
+            .label_formatter(move |_name, point| {
+                let time = chrono::DateTime::from_timestamp_millis(point.x as i64)
+                    .map(|time| time.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M").to_string())
+                    .unwrap_or_default();
+                format!("{} @ {time}", format_currency_with_symbol(point.y, precision, symbol.as_str()))
+            })
+    }
+
     fn render_network_info(&self, core: &Core, ui : &mut Ui) {
 
         CollapsingHeader::new(i18n("Statistics"))
@@ -1,6 +1,10 @@
 use crate::imports::*;
+use crate::probe::{probe_endpoints, Endpoint, EndpointKind, EndpointProbe};
 use crate::settings::{NodeSettings, RpcKind};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::settings::TondidTerminationMethod;
 use crate::egui::theme_color;
+use tondi_metrics_core::Metric;
 
 pub struct Settings {
     #[allow(dead_code)]
@@ -11,6 +15,9 @@ pub struct Settings {
     grpc_network_interface : NetworkInterfaceEditor,
     reset_settings : bool,
     temp_devnet_url_input: String, // Temporary storage for devnet URL input
+    node_profile_name: String,
+    endpoint_probes: Vec<EndpointProbe>,
+    market_currencies: String,
 }
 
 impl Settings {
@@ -23,6 +30,9 @@ impl Settings {
             grpc_network_interface : NetworkInterfaceEditor::default(),
             reset_settings : false,
             temp_devnet_url_input: String::new(),
+            node_profile_name: String::new(),
+            endpoint_probes: Vec::new(),
+            market_currencies: String::new(),
         }
     }
 
@@ -32,15 +42,10 @@ impl Settings {
         self.wrpc_borsh_network_interface = NetworkInterfaceEditor::from(&self.settings.node.wrpc_borsh_network_interface);
         self.wrpc_json_network_interface = NetworkInterfaceEditor::from(&self.settings.node.wrpc_json_network_interface);
         self.grpc_network_interface = NetworkInterfaceEditor::from(&self.settings.node.grpc_network_interface);
+        self.market_currencies = self.settings.market.currencies.join(", ");
     }
 
     pub fn change_current_network(&mut self, network : Network) {
-        // Clear devnet custom URL when switching away from devnet
-        if self.settings.node.network == Network::Devnet && network != Network::Devnet {
-            self.settings.node.devnet_custom_url = None;
-            self.temp_devnet_url_input.clear();
-        }
-        
         self.settings.node.network = network;
     }
 
@@ -85,9 +90,19 @@ impl Settings {
                                     // 显示当前gRPC配置
                                     ui.label(format!("当前gRPC配置: {}", settings.grpc_network_interface.custom));
                                     
-                                    // 对于devnet，显示远程节点地址
-                                    if settings.network == Network::Devnet {
-                                        ui.label(RichText::new("Devnet远程节点: 8.210.45.192:16610").color(theme_color().strong_color));
+                                    ui.label(RichText::new(format!("{} {}", i18n("Network default:"), network_defaults().get(settings.network).grpc)).color(theme_color().strong_color));
+
+                                    ui.add_space(8.);
+                                    ui.label(i18n("Failover endpoints (one per line, in order of preference):"));
+                                    let mut endpoints = settings.failover_endpoints.get(&settings.network).map(|endpoints| endpoints.join("\n")).unwrap_or_default();
+                                    if ui.add(TextEdit::multiline(&mut endpoints).hint_text("127.0.0.1:16610").desired_rows(3)).changed() {
+                                        settings.failover_endpoints.insert(settings.network, endpoints.split('\n').map(String::from).collect());
+                                    }
+
+                                    let invalid = endpoints.split('\n').filter(|endpoint| !endpoint.trim().is_empty()).find_map(|endpoint| NodeSettings::parse_failover_endpoint(endpoint).err());
+                                    if let Some(err) = invalid {
+                                        ui.label(RichText::new(err.to_string()).color(theme_color().warning_color));
+                                        node_settings_error = Some(i18n("Invalid failover endpoint"));
                                     }
                                 });
                         },
@@ -176,6 +191,11 @@ impl Settings {
         core: &mut Core,
         ui: &mut egui::Ui,
     ) {
+        // A profile switched from the status bar replaces the node settings as a whole
+        if self.settings.active_node_profile != core.settings.active_node_profile {
+            self.load(core.settings.clone());
+        }
+
         // Sync settings from core to ensure we have the latest values
         if self.settings.node.network != core.settings.node.network {
             self.settings.node.network = core.settings.node.network;
@@ -331,6 +351,27 @@ impl Settings {
                                 });
                         }
 
+                        #[cfg(not(target_arch = "wasm32"))]
+                        if self.settings.node.node_kind.is_daemon() {
+                            CollapsingHeader::new(i18n("Daemon Shutdown"))
+                                .default_open(false)
+                                .show(ui, |ui| {
+                                    ui.horizontal_wrapped(|ui|{
+                                        TondidTerminationMethod::iter().for_each(|method| {
+                                            ui.radio_value(&mut self.settings.node.tondid_daemon_termination, *method, method.to_string());
+                                        });
+                                    });
+                                    ui.label(self.settings.node.tondid_daemon_termination.describe());
+                                    if self.settings.node.tondid_daemon_termination == TondidTerminationMethod::Sigterm {
+                                        ui.horizontal(|ui|{
+                                            ui.label(i18n("Shutdown timeout:"));
+                                            ui.add(DragValue::new(&mut self.settings.node.tondid_daemon_termination_timeout).range(5..=600).suffix(" sec"));
+                                        });
+                                    }
+                                    ui.label(RichText::new(i18n("Changes take effect the next time the node is started.")).small());
+                                });
+                        }
+
                         #[cfg(not(target_arch = "wasm32"))]
                         if self.settings.node.node_kind.is_config_capable() {
                             CollapsingHeader::new(i18n("Data Storage"))
@@ -440,6 +481,7 @@ impl Settings {
 
                 if self.settings.node.node_kind == TondidNodeKind::Remote {
                     node_settings_error = Self::render_remote_settings(core, ui, &mut self.settings.node, &mut self.grpc_network_interface);
+                    self.render_endpoint_probe(ui);
                 }
 
                 #[cfg(not(target_arch = "wasm32"))]
@@ -519,6 +561,8 @@ impl Settings {
                         match response {
                             Confirm::Ack => {
 
+                                self.settings.node_profiles = core.settings.node_profiles.clone();
+                                self.settings.active_node_profile = None;
                                 core.settings = self.settings.clone();
                                 core.settings.store_sync().unwrap();
 
@@ -547,6 +591,173 @@ impl Settings {
 
 
 
+    fn render_endpoint_probe(
+        &mut self,
+        ui: &mut egui::Ui,
+    ) {
+        let probe_result = Payload::<Result<Vec<EndpointProbe>>>::new("endpoint_probe_result");
+        if let Some(result) = probe_result.take() {
+            match result {
+                Ok(probes) => self.endpoint_probes = probes,
+                Err(err) => runtime().error(err.to_string()),
+            }
+        }
+
+        CollapsingHeader::new(i18n("Endpoint Health"))
+            .default_open(false)
+            .show(ui, |ui| {
+
+                ui.horizontal(|ui| {
+                    if probe_result.is_pending() {
+                        ui.spinner();
+                        ui.label(i18n("Probing endpoints..."));
+                    } else if ui.button(i18n("Probe Endpoints")).clicked() {
+                        let network = self.settings.node.network;
+                        let endpoints = Endpoint::configured(&self.settings, network);
+                        spawn_with_result(&probe_result, async move {
+                            probe_endpoints(endpoints, network).await
+                        });
+                    }
+                });
+
+                if self.endpoint_probes.is_empty() {
+                    return;
+                }
+
+                ui.add_space(4.);
+
+                let mut selected = None;
+                Grid::new("endpoint_probe_grid")
+                    .num_columns(8)
+                    .spacing([16.0,4.0])
+                    .striped(true)
+                    .show(ui, |ui| {
+                        ui.strong(i18n("Endpoint"));
+                        ui.strong(i18n("Type"));
+                        ui.strong(i18n("Source"));
+                        ui.strong(i18n("Latency"));
+                        ui.strong(i18n("Synced"));
+                        ui.strong(i18n("Version"));
+                        ui.strong(i18n("Network"));
+                        ui.label("");
+                        ui.end_row();
+
+                        for probe in self.endpoint_probes.iter() {
+                            ui.label(&probe.endpoint.url);
+                            ui.label(probe.endpoint.kind.to_string());
+                            ui.label(&probe.endpoint.source);
+
+                            if let Some(error) = probe.error.as_ref() {
+                                ui.colored_label(theme_color().error_color, i18n("Error")).on_hover_text(error);
+                            } else if let Some(latency) = probe.latency {
+                                ui.label(format!("{} ms", latency.as_millis()));
+                            } else {
+                                ui.label("-");
+                            }
+
+                            match probe.is_synced {
+                                Some(true) => { ui.label(i18n("Yes")); },
+                                Some(false) => { ui.colored_label(theme_color().warning_color, i18n("No")); },
+                                None => { ui.label("-"); },
+                            }
+
+                            ui.label(probe.server_version.as_deref().unwrap_or("-"));
+                            ui.label(probe.network_id.as_deref().unwrap_or("-"));
+
+                            if ui.add_enabled(probe.error.is_none(), Button::new(i18n("Use"))).clicked() {
+                                selected = Some(probe.endpoint.clone());
+                            }
+                            ui.end_row();
+                        }
+                    });
+
+                if let Some(endpoint) = selected {
+                    let node = &mut self.settings.node;
+                    match endpoint.kind {
+                        EndpointKind::Grpc => {
+                            if let Ok(network_interface) = NodeSettings::parse_failover_endpoint(&endpoint.url) {
+                                self.grpc_network_interface = NetworkInterfaceEditor::from(&network_interface);
+                                node.grpc_network_interface = network_interface;
+                                node.rpc_kind = RpcKind::Grpc;
+                            }
+                        }
+                        EndpointKind::Wrpc(encoding) => {
+                            node.wrpc_url = endpoint.url;
+                            node.wrpc_encoding = encoding;
+                            node.rpc_kind = RpcKind::Wrpc;
+                        }
+                    }
+                    node.connection_config_kind = NodeConnectionConfigKind::Custom;
+                }
+            });
+    }
+
+    fn render_node_profiles(
+        &mut self,
+        core: &mut Core,
+        ui: &mut egui::Ui,
+    ) {
+        CollapsingHeader::new(i18n("Node Profiles"))
+            .default_open(false)
+            .show(ui, |ui| {
+
+                ui.label(i18n("Save the current node connection as a named profile to switch between nodes from the status bar."));
+                ui.add_space(4.);
+
+                let mut remove = None;
+                Grid::new("node_profiles_grid")
+                    .num_columns(4)
+                    .spacing([16.0,4.0])
+                    .show(ui, |ui| {
+                        for profile in core.settings.node_profiles.iter() {
+                            let is_active = core.settings.active_node_profile.as_ref() == Some(&profile.name);
+                            if is_active {
+                                ui.colored_label(theme_color().strong_color, &profile.name);
+                            } else {
+                                ui.label(&profile.name);
+                            }
+                            ui.label(format!("{} · {}", profile.network, profile.node_kind));
+                            ui.label(if profile.url.is_empty() { profile.connection_config_kind.to_string() } else { profile.url.clone() });
+                            if ui.small_button(i18n("Remove")).clicked() {
+                                remove = Some(profile.name.clone());
+                            }
+                            ui.end_row();
+                        }
+                    });
+
+                if let Some(name) = remove {
+                    core.settings.node_profiles.retain(|profile| profile.name != name);
+                    if core.settings.active_node_profile.as_ref() == Some(&name) {
+                        core.settings.active_node_profile = None;
+                    }
+                    self.settings.node_profiles = core.settings.node_profiles.clone();
+                    self.settings.active_node_profile = core.settings.active_node_profile.clone();
+                    core.store_settings();
+                }
+
+                ui.add_space(4.);
+                ui.horizontal(|ui| {
+                    ui.label(i18n("Profile name:"));
+                    ui.add(TextEdit::singleline(&mut self.node_profile_name).desired_width(160.));
+
+                    let name = self.node_profile_name.trim().to_string();
+                    if ui.add_enabled(!name.is_empty(), Button::new(i18n("Save Current Settings"))).clicked() {
+                        let profile = NodeProfile::from_node_settings(&name, &core.settings.node);
+                        if let Some(existing) = core.settings.node_profiles.iter_mut().find(|profile| profile.name == name) {
+                            *existing = profile;
+                        } else {
+                            core.settings.node_profiles.push(profile);
+                        }
+                        core.settings.active_node_profile = Some(name);
+                        self.settings.node_profiles = core.settings.node_profiles.clone();
+                        self.settings.active_node_profile = core.settings.active_node_profile.clone();
+                        core.store_settings();
+                        self.node_profile_name.clear();
+                    }
+                });
+            });
+    }
+
     fn render_ui_settings(
         &mut self,
         core: &mut Core,
@@ -622,6 +833,38 @@ impl Settings {
                             ui.add_space(1.);
                         });
 
+                        CollapsingHeader::new(i18n("Fiat Values"))
+                            .default_open(true)
+                            .show(ui, |ui| {
+                                let user_interface = &mut self.settings.user_interface;
+                                ui.checkbox(&mut user_interface.fiat, i18n("Show fiat values next to amounts"));
+
+                                ui.add_enabled_ui(user_interface.fiat, |ui| {
+                                    ui.horizontal(|ui| {
+                                        ui.label(i18n("Currency:"));
+                                        egui::ComboBox::from_id_salt("fiat_currency")
+                                            .selected_text(user_interface.fiat_currency.to_uppercase())
+                                            .show_ui(ui, |ui| {
+                                                for currency in core.settings.market.currencies.iter() {
+                                                    ui.selectable_value(&mut user_interface.fiat_currency, currency.clone(), currency.to_uppercase());
+                                                }
+                                            });
+                                    });
+                                });
+
+                                if user_interface.fiat && !core.settings.market_monitor {
+                                    ui.label(RichText::new(i18n("Fiat values require the Market Monitor to be enabled.")).color(theme_color().warning_color));
+                                }
+
+                                if user_interface.fiat != core.settings.user_interface.fiat
+                                    || user_interface.fiat_currency != core.settings.user_interface.fiat_currency
+                                {
+                                    core.settings.user_interface.fiat = user_interface.fiat;
+                                    core.settings.user_interface.fiat_currency = user_interface.fiat_currency.clone();
+                                    core.store_settings();
+                                }
+                            });
+
                         if workflow_core::runtime::is_native() {
                             CollapsingHeader::new(i18n("Zoom"))
                                 .default_open(true)
@@ -692,6 +935,161 @@ impl Settings {
 
     }
 
+    fn render_metric_alerts(
+        &mut self,
+        core: &mut Core,
+        ui: &mut egui::Ui,
+    ) {
+        CollapsingHeader::new(i18n("Metric Alerts"))
+            .default_open(false)
+            .show(ui, |ui| {
+
+                ui.label(i18n("Show a notification when a node metric stays above, below or unchanged for the given duration."));
+                ui.add_space(4.);
+
+                let alerts = &mut self.settings.user_interface.metrics.alerts;
+                let mut remove = None;
+                Grid::new("metric_alerts_grid")
+                    .num_columns(6)
+                    .spacing([8.0,4.0])
+                    .show(ui, |ui| {
+                        for (index, rule) in alerts.iter_mut().enumerate() {
+                            ui.checkbox(&mut rule.enable, "");
+                            egui::ComboBox::from_id_salt(("metric_alert_metric", index))
+                                .selected_text(i18n(rule.metric.title().0))
+                                .width(180.)
+                                .show_ui(ui, |ui| {
+                                    for metric in Metric::into_iter() {
+                                        ui.selectable_value(&mut rule.metric, metric, i18n(metric.title().0));
+                                    }
+                                });
+                            egui::ComboBox::from_id_salt(("metric_alert_condition", index))
+                                .selected_text(rule.condition.to_string())
+                                .show_ui(ui, |ui| {
+                                    for condition in MetricAlertCondition::iter() {
+                                        ui.selectable_value(&mut rule.condition, *condition, condition.to_string());
+                                    }
+                                });
+                            if rule.condition.has_threshold() {
+                                ui.add(DragValue::new(&mut rule.threshold).speed(1.0));
+                            } else {
+                                ui.label("");
+                            }
+                            ui.horizontal(|ui| {
+                                ui.label(i18n("for"));
+                                ui.add(DragValue::new(&mut rule.duration).range(0..=86400).suffix(" sec"));
+                            });
+                            if ui.small_button(i18n("Remove")).clicked() {
+                                remove = Some(index);
+                            }
+                            ui.end_row();
+                        }
+                    });
+
+                if let Some(index) = remove {
+                    alerts.remove(index);
+                }
+
+                ui.add_space(4.);
+                if ui.button(i18n("Add Rule")).clicked() {
+                    alerts.push(MetricAlertRule::default());
+                }
+
+                if *alerts != core.settings.user_interface.metrics.alerts {
+                    ui.add_space(4.);
+                    ui.horizontal(|ui| {
+                        if ui.button(i18n("Apply")).clicked() {
+                            core.settings.user_interface.metrics.alerts = alerts.clone();
+                            self.runtime.metrics_service().update_alert_rules(alerts);
+                            core.store_settings();
+                        }
+                        if ui.button(i18n("Cancel")).clicked() {
+                            *alerts = core.settings.user_interface.metrics.alerts.clone();
+                        }
+                    });
+                }
+            });
+    }
+
+    fn render_market_monitor_settings(&mut self, core: &mut Core, ui: &mut egui::Ui) {
+        let market = &mut self.settings.market;
+
+        ui.add_space(4.);
+        Grid::new("market_monitor_settings_grid")
+            .num_columns(2)
+            .spacing([16.0, 4.0])
+            .show(ui, |ui| {
+                ui.label(i18n("Provider:"));
+                egui::ComboBox::from_id_salt("market_data_provider")
+                    .selected_text(i18n(market.provider.describe()))
+                    .show_ui(ui, |ui| {
+                        let providers = [
+                            MarketDataProviderKind::CoinGecko,
+                            MarketDataProviderKind::CoinMarketCap,
+                            MarketDataProviderKind::HttpJson { url: String::new() },
+                            MarketDataProviderKind::FixedRate { rates: Default::default() },
+                        ];
+                        for provider in providers {
+                            let selected = std::mem::discriminant(&provider) == std::mem::discriminant(&market.provider);
+                            if ui.selectable_label(selected, i18n(provider.describe())).clicked() && !selected {
+                                market.provider = provider;
+                            }
+                        }
+                    });
+                ui.end_row();
+
+                ui.label(i18n("Currencies:"));
+                if ui.add(TextEdit::singleline(&mut self.market_currencies).desired_width(160.)).changed() {
+                    market.currencies = self.market_currencies
+                        .split(',')
+                        .map(|currency| currency.trim().to_lowercase())
+                        .filter(|currency| !currency.is_empty())
+                        .collect();
+                }
+                ui.end_row();
+
+                ui.label(i18n("Update interval (seconds):"));
+                ui.add(DragValue::new(&mut market.interval).range(crate::settings::MARKET_MONITOR_MIN_INTERVAL..=3600));
+                ui.end_row();
+
+                match &mut market.provider {
+                    MarketDataProviderKind::HttpJson { url } => {
+                        ui.label(i18n("Endpoint URL:"));
+                        ui.add(TextEdit::singleline(url).hint_text("https://").desired_width(240.));
+                        ui.end_row();
+                    }
+                    MarketDataProviderKind::FixedRate { rates } => {
+                        rates.retain(|currency, _| market.currencies.contains(currency));
+                        for currency in market.currencies.iter() {
+                            ui.label(currency.to_uppercase());
+                            ui.add(DragValue::new(rates.entry(currency.clone()).or_insert(0.0)).speed(0.0001).range(0.0..=f64::MAX));
+                            ui.end_row();
+                        }
+                    }
+                    _ => {}
+                }
+            });
+
+        let is_valid = match &self.settings.market.provider {
+            MarketDataProviderKind::HttpJson { url } => url.starts_with("http://") || url.starts_with("https://"),
+            _ => true,
+        } && !self.settings.market.currencies.is_empty();
+
+        if self.settings.market != core.settings.market {
+            ui.horizontal(|ui| {
+                if ui.add_enabled(is_valid, Button::new(i18n("Apply"))).clicked() {
+                    core.settings.market = self.settings.market.clone();
+                    self.runtime.market_monitor_service().update_settings(&core.settings.market);
+                    core.store_settings();
+                }
+                if ui.button(i18n("Cancel")).clicked() {
+                    self.settings.market = core.settings.market.clone();
+                    self.market_currencies = self.settings.market.currencies.join(", ");
+                }
+            });
+        }
+    }
+
     fn render_settings(
         &mut self,
         core: &mut Core,
@@ -700,6 +1098,8 @@ impl Settings {
 
         self.render_node_settings(core,ui);
 
+        self.render_node_profiles(core,ui);
+
         self.render_ui_settings(core,ui);
 
         CollapsingHeader::new(i18n("Services"))
@@ -714,6 +1114,8 @@ impl Settings {
                             self.runtime.market_monitor_service().enable(core.settings.market_monitor);
                             core.store_settings();
                         }
+
+                        self.render_market_monitor_settings(core, ui);
                     });
 
                 #[cfg(not(target_arch = "wasm32"))]
@@ -757,9 +1159,49 @@ impl Settings {
                             core.settings.update_check_interval = self.settings.update_check_interval;
                             core.store_settings();
                         }
-                    });    
+                    });
+
+                #[cfg(not(target_arch = "wasm32"))]
+                CollapsingHeader::new(i18n("Prometheus Exporter"))
+                    .default_open(false)
+                    .show(ui, |ui| {
+                        ui.checkbox(&mut self.settings.prometheus.enable, i18n("Serve node metrics for Prometheus scraping"));
+                        ui.horizontal(|ui| {
+                            ui.label(i18n("Listen address:"));
+                            ui.add(TextEdit::singleline(&mut self.settings.prometheus.listen).desired_width(160.));
+                        });
+
+                        let listen = self.settings.prometheus.listen.trim().parse::<std::net::SocketAddr>();
+                        match &listen {
+                            Ok(address) if self.settings.prometheus.enable => {
+                                ui.label(format!("http://{address}/metrics"));
+                            }
+                            Err(err) => {
+                                ui.colored_label(theme_color().error_color, format!("{}: {err}", i18n("Invalid address")));
+                            }
+                            _ => {}
+                        }
+
+                        if self.settings.prometheus != core.settings.prometheus {
+                            ui.horizontal(|ui| {
+                                if ui.add_enabled(listen.is_ok(), Button::new(i18n("Apply"))).clicked() {
+                                    self.settings.prometheus.listen = self.settings.prometheus.listen.trim().to_string();
+                                    if let Err(err) = self.runtime.metrics_service().update_prometheus_exporter(&self.settings.prometheus) {
+                                        runtime().error(format!("{}: {err}", i18n("Unable to start Prometheus exporter")));
+                                    }
+                                    core.settings.prometheus = self.settings.prometheus.clone();
+                                    core.store_settings();
+                                }
+                                if ui.button(i18n("Cancel")).clicked() {
+                                    self.settings.prometheus = core.settings.prometheus.clone();
+                                }
+                            });
+                        }
+                    });
             });
 
+        self.render_metric_alerts(core,ui);
+
         CollapsingHeader::new(i18n("Network Fee Estimator"))
             .default_open(false)
             .show(ui, |ui| {
@@ -776,7 +1218,13 @@ impl Settings {
             });
             
         #[cfg(not(target_arch = "wasm32"))]
-        core.storage.clone().render_settings(core, ui);
+        {
+            if core.storage.clone().update_relocation(core) {
+                self.settings.node.tondid_daemon_storage_folder_enable = core.settings.node.tondid_daemon_storage_folder_enable;
+                self.settings.node.tondid_daemon_storage_folder = core.settings.node.tondid_daemon_storage_folder.clone();
+            }
+            core.storage.clone().render_settings(core, ui);
+        }
 
         CollapsingHeader::new(i18n("Advanced"))
             .default_open(false)
@@ -0,0 +1,137 @@
+//! User notes and tags attached to transactions and addresses, stored
+//! per account in a `<wallet>.notes` file next to the wallet file.
+
+use crate::imports::*;
+use crate::sidecar::Sidecar;
+use std::collections::BTreeMap;
+
+#[derive(Default, Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Note {
+    pub text: String,
+    pub tags: Vec<String>,
+}
+
+impl Note {
+    pub fn new(text: &str, tags: &str) -> Self {
+        Self {
+            text: text.trim().to_string(),
+            tags: Self::parse_tags(tags),
+        }
+    }
+
+    /// Parse a comma or whitespace separated list of tags, dropping
+    /// the optional `#` prefix and duplicates.
+    pub fn parse_tags(text: &str) -> Vec<String> {
+        let mut tags = Vec::<String>::new();
+        for tag in text.split(|c: char| c == ',' || c.is_whitespace()) {
+            let tag = tag.trim().trim_start_matches('#');
+            if !tag.is_empty() && !tags.iter().any(|existing| existing.eq_ignore_ascii_case(tag)) {
+                tags.push(tag.to_string());
+            }
+        }
+        tags
+    }
+
+    pub fn tags_as_string(&self) -> String {
+        self.tags.join(", ")
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty() && self.tags.is_empty()
+    }
+
+    /// Case-insensitive match against a lowercase search term.
+    pub fn matches(&self, search: &str) -> bool {
+        self.text.to_lowercase().contains(search)
+            || self.tags.iter().any(|tag| tag.to_lowercase().contains(search))
+    }
+}
+
+impl std::fmt::Display for Note {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text)?;
+        for tag in self.tags.iter() {
+            write!(f, " #{tag}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct AccountNotes {
+    #[serde(default)]
+    pub transactions: BTreeMap<String, Note>,
+    #[serde(default)]
+    pub addresses: BTreeMap<String, Note>,
+}
+
+impl AccountNotes {
+    pub fn transaction(&self, id: &TransactionId) -> Option<&Note> {
+        self.transactions.get(&id.to_string())
+    }
+
+    pub fn address(&self, address: &Address) -> Option<&Note> {
+        self.addresses.get(&address.to_string())
+    }
+
+    pub fn set_transaction(&mut self, id: &TransactionId, note: Note) {
+        if note.is_empty() {
+            self.transactions.remove(&id.to_string());
+        } else {
+            self.transactions.insert(id.to_string(), note);
+        }
+    }
+
+    pub fn set_address(&mut self, address: &Address, note: Note) {
+        if note.is_empty() {
+            self.addresses.remove(&address.to_string());
+        } else {
+            self.addresses.insert(address.to_string(), note);
+        }
+    }
+
+    /// Check the transaction note and the notes of the given
+    /// addresses against a lowercase search term.
+    pub fn matches(&self, id: &TransactionId, addresses: &[Address], search: &str) -> bool {
+        self.transaction(id).is_some_and(|note| note.matches(search))
+            || addresses.iter().any(|address| self.address(address).is_some_and(|note| note.matches(search)))
+    }
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct WalletNotes {
+    #[serde(default)]
+    pub accounts: BTreeMap<String, AccountNotes>,
+}
+
+impl Sidecar for WalletNotes {
+    const EXTENSION: &'static str = "notes";
+}
+
+impl WalletNotes {
+    pub fn account(&self, account_id: &AccountId) -> Option<&AccountNotes> {
+        self.accounts.get(&account_id.to_string())
+    }
+
+    pub fn account_mut(&mut self, account_id: &AccountId) -> &mut AccountNotes {
+        self.accounts.entry(account_id.to_string()).or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_note_tags() {
+        assert_eq!(Note::parse_tags("invoice, #Q3  rent,invoice"), vec!["invoice", "Q3", "rent"]);
+
+        let note = Note::new(" Invoice 1042 ", "#clients, acme");
+        assert_eq!(note.text, "Invoice 1042");
+        assert_eq!(note.to_string(), "Invoice 1042 #clients #acme");
+        assert!(note.matches("1042"));
+        assert!(note.matches("acme"));
+        assert!(!note.matches("rent"));
+        assert!(Note::new("", " , ").is_empty());
+    }
+}
@@ -1,4 +1,5 @@
 use crate::imports::*;
+use crate::market::Fiat;
 use egui_phosphor::light::*;
 use tondi_consensus_core::tx::{TransactionInput, TransactionOutpoint, TransactionOutput};
 use tondi_txscript::standard::extract_script_pub_key_address;
@@ -88,6 +89,47 @@ impl Transaction {
     pub fn aggregate_input_value(&self) -> u64 {
         self.context().record.aggregate_input_value()
     }
+
+    pub fn record(&self) -> Arc<TransactionRecord> {
+        self.context().record.clone()
+    }
+
+    pub fn fees(&self) -> Option<u64> {
+        match self.context().record.transaction_data() {
+            TransactionData::Outgoing { fees, .. }
+            | TransactionData::TransferOutgoing { fees, .. }
+            | TransactionData::Batch { fees, .. } => Some(*fees),
+            _ => None,
+        }
+    }
+
+    /// Addresses of the received UTXOs for incoming transactions
+    /// or of the transaction outputs for outgoing transactions.
+    pub fn addresses(&self, network: Network) -> Vec<Address> {
+        match self.context().record.transaction_data() {
+            TransactionData::Reorg { utxo_entries, .. }
+            | TransactionData::Stasis { utxo_entries, .. }
+            | TransactionData::Incoming { utxo_entries, .. }
+            | TransactionData::TransferIncoming { utxo_entries, .. }
+            | TransactionData::External { utxo_entries, .. } => utxo_entries
+                .iter()
+                .filter_map(|utxo| utxo.address.clone())
+                .collect(),
+            TransactionData::Outgoing { transaction, .. }
+            | TransactionData::TransferOutgoing { transaction, .. }
+            | TransactionData::Batch { transaction, .. }
+            | TransactionData::Change { transaction, .. } => {
+                let address_prefix: tondi_addresses::Prefix = network.into();
+                transaction
+                    .outputs
+                    .iter()
+                    .filter_map(|output| {
+                        extract_script_pub_key_address(&output.script_public_key, address_prefix).ok()
+                    })
+                    .collect()
+            }
+        }
+    }
 }
 
 impl IdT for Transaction {
@@ -131,6 +173,7 @@ impl Transaction {
         current_daa_score: Option<u64>,
         _include_utxos: bool,
         largest: Option<u64>,
+        fiat: Option<&Fiat<'_>>,
     ) {
         let width = ui.available_width() / ui.ctx().pixels_per_point();
         let w_min = 250.0;
@@ -147,6 +190,8 @@ impl Transaction {
 
         let ps2k = |sompi| padded_sompi_to_tondi_string_with_suffix(sompi, &network_type, padding);
         let s2k = |sompi| sompi_to_tondi_string_with_suffix(sompi, &network_type);
+        // fiat value at the time of the transaction
+        let s2f = |sompi| fiat.map(|fiat| fiat.format_at(sompi, record.unixtime_msec));
 
         let timestamp = record
             .unixtime_as_locale_string()
@@ -219,6 +264,10 @@ impl Transaction {
                     .text(timestamp.as_str(), default_color)
                     .text(&value, TransactionKind::Incoming.as_color());
 
+                if let Some(fiat_value) = s2f(record.value()) {
+                    job = job.text(&fiat_value, default_color);
+                }
+
                 // ui.LayoutJobBuilder::new(width,8.0(&transaction_id, false, |ui,state| {
                 //     ui.horizontal( |ui| {
 
@@ -361,6 +410,10 @@ impl Transaction {
                         TransactionKind::Outgoing.as_color(),
                     );
 
+                    if let Some(fiat_value) = s2f(*payment_value + *fees) {
+                        job = job.text(&fiat_value, default_color);
+                    }
+
                     if !maturity.unwrap_or(true) {
                         job = job.text("Submitting...", strong_color);
                     }
@@ -470,16 +523,22 @@ impl Transaction {
                     }
 
                     if let Some(payment_value) = payment_value {
-                        ljb(&content)
+                        let mut job = ljb(&content)
                             .padded(15, "Amount:", default_color)
-                            .text(&ps2k(*payment_value), TransactionKind::Outgoing.as_color())
-                            .label(ui);
+                            .text(&ps2k(*payment_value), TransactionKind::Outgoing.as_color());
+                        if let Some(fiat_value) = s2f(*payment_value) {
+                            job = job.text(&fiat_value, default_color);
+                        }
+                        job.label(ui);
                     }
 
-                    ljb(&content)
+                    let mut job = ljb(&content)
                         .padded(15, "Fees:", default_color)
-                        .text(&ps2k(*fees), TransactionKind::Outgoing.as_color())
-                        .label(ui);
+                        .text(&ps2k(*fees), TransactionKind::Outgoing.as_color());
+                    if let Some(fiat_value) = s2f(*fees) {
+                        job = job.text(&fiat_value, default_color);
+                    }
+                    job.label(ui);
 
                     ljb(&content)
                         .padded(15, "Inputs:", default_color)
@@ -0,0 +1,259 @@
+use tondi_wrpc_client::Resolver;
+
+use crate::imports::*;
+use crate::settings::RpcKind;
+
+/// Time allowed for each step of an endpoint probe (the connection
+/// and every individual query).
+const PROBE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Public node entry as listed by the resolver `/status` endpoint.
+#[derive(Clone, Debug, Deserialize)]
+struct PublicNode {
+    url: String,
+    #[serde(default)]
+    provider_name: Option<String>,
+    encoding: WrpcEncoding,
+    network: Network,
+    online: bool,
+}
+
+/// Fetch the online public nodes of `network` from the first
+/// reachable resolver.
+async fn fetch_public_nodes(network: Network) -> Result<Vec<PublicNode>> {
+    let resolvers = Resolver::default().urls().unwrap_or_default();
+    for resolver in resolvers {
+        if let Ok(nodes) = workflow_http::get_json::<Vec<PublicNode>>(format!("{}/status", resolver)).await {
+            let secure_only = is_secure_context();
+            return Ok(nodes
+                .into_iter()
+                .filter(|node| node.network == network && node.online)
+                .filter(|node| !secure_only || node.url.starts_with("wss://") || node.url.starts_with("wrpcs://"))
+                .collect());
+        }
+    }
+
+    Err(Error::custom("Unable to connect to any resolver"))
+}
+
+/// Pages served over https can only connect to secure websockets.
+fn is_secure_context() -> bool {
+    cfg_if! {
+        if #[cfg(target_arch = "wasm32")] {
+            window().location().protocol().map(|protocol| protocol == "https:").unwrap_or(false)
+        } else {
+            false
+        }
+    }
+}
+
+/// Run `future`, failing with a timeout error after [`PROBE_TIMEOUT`].
+async fn with_timeout<T>(future: impl Future<Output = Result<T>>) -> Result<T> {
+    select! {
+        result = future.fuse() => result,
+        _ = task::sleep(PROBE_TIMEOUT).fuse() => Err(Error::custom(i18n("Timeout"))),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EndpointKind {
+    Grpc,
+    Wrpc(WrpcEncoding),
+}
+
+impl std::fmt::Display for EndpointKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EndpointKind::Grpc => write!(f, "gRPC"),
+            EndpointKind::Wrpc(encoding) => write!(f, "wRPC ({encoding})"),
+        }
+    }
+}
+
+/// An RPC endpoint that can be probed.
+#[derive(Clone, Debug)]
+pub struct Endpoint {
+    pub kind: EndpointKind,
+    pub url: String,
+    /// Where the endpoint comes from (node settings, a profile, the public resolver).
+    pub source: String,
+}
+
+impl Endpoint {
+    pub fn grpc(url: impl Into<String>, source: impl Into<String>) -> Self {
+        Self {
+            kind: EndpointKind::Grpc,
+            url: url.into(),
+            source: source.into(),
+        }
+    }
+
+    pub fn wrpc(url: impl Into<String>, encoding: WrpcEncoding, source: impl Into<String>) -> Self {
+        Self {
+            kind: EndpointKind::Wrpc(encoding),
+            url: url.into(),
+            source: source.into(),
+        }
+    }
+
+    /// Collect the endpoints configured in the node settings and node
+    /// profiles for the given network, without duplicates.
+    pub fn configured(settings: &Settings, network: Network) -> Vec<Endpoint> {
+        let node = &settings.node;
+        let mut endpoints = vec![];
+
+        if node.network == network {
+            endpoints.push(Endpoint::grpc(node.grpc_network_interface.to_string(), i18n("Node Settings")));
+            endpoints.extend(node.grpc_failover_endpoints().into_iter().map(|endpoint| Endpoint::grpc(endpoint.to_string(), i18n("Failover"))));
+            if !node.wrpc_url.is_empty() {
+                endpoints.push(Endpoint::wrpc(node.wrpc_url.clone(), node.wrpc_encoding, i18n("Node Settings")));
+            }
+        }
+
+        for profile in settings.node_profiles.iter().filter(|profile| profile.network == network && !profile.url.is_empty()) {
+            let endpoint = match profile.rpc_kind {
+                RpcKind::Grpc => Endpoint::grpc(profile.url.clone(), profile.name.clone()),
+                RpcKind::Wrpc => Endpoint::wrpc(profile.url.clone(), profile.wrpc_encoding, profile.name.clone()),
+            };
+            endpoints.push(endpoint);
+        }
+
+        let mut seen = AHashSet::new();
+        endpoints.retain(|endpoint| seen.insert((endpoint.kind.clone(), endpoint.url.clone())));
+        endpoints
+    }
+}
+
+/// Result of probing a single endpoint.
+#[derive(Clone, Debug)]
+pub struct EndpointProbe {
+    pub endpoint: Endpoint,
+    pub latency: Option<Duration>,
+    pub is_synced: Option<bool>,
+    pub server_version: Option<String>,
+    pub network_id: Option<String>,
+    pub error: Option<String>,
+}
+
+impl EndpointProbe {
+    pub fn is_healthy(&self) -> bool {
+        self.error.is_none() && self.is_synced == Some(true)
+    }
+}
+
+async fn connect_endpoint(endpoint: &Endpoint, network: Network) -> Result<Arc<dyn RpcApi>> {
+    match &endpoint.kind {
+        EndpointKind::Grpc => {
+            cfg_if! {
+                if #[cfg(not(target_arch = "wasm32"))] {
+                    use crate::runtime::services::tondi::TondiGrpcClient;
+
+                    let network_interface = NodeSettings::parse_failover_endpoint(&endpoint.url)?;
+                    let client = TondiGrpcClient::connect(network_interface, network).await?;
+                    if !client.is_connected() {
+                        return Err(Error::custom(format!("Unable to connect to {}", endpoint.url)));
+                    }
+                    Ok(Arc::new(client))
+                } else {
+                    let _ = network;
+                    Err(Error::custom("gRPC is not supported in the web version"))
+                }
+            }
+        }
+        EndpointKind::Wrpc(encoding) => {
+            use tondi_wallet_core::rpc::{ConnectOptions, ConnectStrategy};
+
+            let network_id = NetworkId::from(network);
+            let url = TondiRpcClient::parse_url(endpoint.url.clone(), *encoding, network_id.into())?;
+            let client = Arc::new(TondiRpcClient::new_with_args(
+                *encoding,
+                Some(url.as_str()),
+                None,
+                Some(network_id),
+                None,
+            )?);
+            client
+                .connect(Some(ConnectOptions {
+                    block_async_connect: true,
+                    strategy: ConnectStrategy::Fallback,
+                    url: None,
+                    connect_timeout: Some(PROBE_TIMEOUT),
+                    retry_interval: None,
+                }))
+                .await?;
+            Ok(client)
+        }
+    }
+}
+
+async fn disconnect_endpoint(rpc_api: Arc<dyn RpcApi>) {
+    if let Ok(client) = rpc_api.clone().downcast_arc::<TondiRpcClient>() {
+        client.disconnect().await.ok();
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Ok(client) = rpc_api.downcast_arc::<crate::runtime::services::tondi::TondiGrpcClient>() {
+        client.disconnect().await.ok();
+    }
+}
+
+async fn query_endpoint(rpc_api: &Arc<dyn RpcApi>, probe: &mut EndpointProbe) -> Result<()> {
+    let start = Instant::now();
+    with_timeout(async { Ok(rpc_api.ping().await?) }).await?;
+    probe.latency = Some(start.elapsed());
+
+    let info = with_timeout(async { Ok(rpc_api.get_server_info().await?) }).await?;
+    probe.is_synced = Some(info.is_synced);
+    probe.server_version = Some(info.server_version);
+    probe.network_id = Some(info.network_id.to_string());
+    Ok(())
+}
+
+/// Measure round-trip latency and query the sync state, server
+/// version and network id of the endpoint.
+pub async fn probe_endpoint(endpoint: Endpoint, network: Network) -> EndpointProbe {
+    let mut probe = EndpointProbe {
+        endpoint: endpoint.clone(),
+        latency: None,
+        is_synced: None,
+        server_version: None,
+        network_id: None,
+        error: None,
+    };
+
+    // the client is held outside of the timed queries so that
+    // it is always disconnected, even if a query times out
+    let result = match with_timeout(connect_endpoint(&endpoint, network)).await {
+        Ok(rpc_api) => {
+            let result = query_endpoint(&rpc_api, &mut probe).await;
+            disconnect_endpoint(rpc_api).await;
+            result
+        }
+        Err(err) => Err(err),
+    };
+
+    if let Err(err) = result {
+        probe.error = Some(err.to_string());
+    }
+
+    probe
+}
+
+/// Probe the given endpoints together with the public nodes listed by
+/// the resolver. Results are sorted with healthy endpoints first, by latency.
+pub async fn probe_endpoints(mut endpoints: Vec<Endpoint>, network: Network) -> Result<Vec<EndpointProbe>> {
+    match fetch_public_nodes(network).await {
+        Ok(nodes) => {
+            endpoints.extend(nodes.into_iter().map(|node| {
+                let source = node.provider_name.unwrap_or_else(|| i18n("Public Node").to_string());
+                Endpoint::wrpc(node.url, node.encoding, source)
+            }));
+        }
+        Err(err) => {
+            log_warn!("Unable to fetch public nodes: {}", err);
+        }
+    }
+
+    let mut probes = join_all(endpoints.into_iter().map(|endpoint| probe_endpoint(endpoint, network))).await;
+    probes.sort_by_key(|probe| (!probe.is_healthy(), probe.latency.unwrap_or(Duration::MAX)));
+    Ok(probes)
+}
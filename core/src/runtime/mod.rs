@@ -43,6 +43,8 @@ pub struct Inner {
     metrics_service: Arc<MetricsService>,
     #[cfg(not(feature = "lean"))]
     block_dag_monitor_service: Arc<BlockDagMonitorService>,
+    #[cfg(not(feature = "lean"))]
+    mempool_monitor_service: Arc<MempoolMonitorService>,
 }
 
 /// Runtime is a core component of the Tondi Dashboard application responsible for
@@ -97,6 +99,10 @@ impl Runtime {
                     application_events.clone(),
                     settings,
                 ));
+                let mempool_monitor_service = Arc::new(MempoolMonitorService::new(
+                    application_events.clone(),
+                    settings,
+                ));
             }
         }
         // let metrics_service = Arc::new(MetricsService::new(application_events.clone(), settings));
@@ -116,6 +122,8 @@ impl Runtime {
             metrics_service.clone(),
             #[cfg(not(feature = "lean"))]
             block_dag_monitor_service.clone(),
+            #[cfg(not(feature = "lean"))]
+            mempool_monitor_service.clone(),
         ]);
 
         let runtime = Self {
@@ -137,6 +145,8 @@ impl Runtime {
                 metrics_service,
                 #[cfg(not(feature = "lean"))]
                 block_dag_monitor_service,
+                #[cfg(not(feature = "lean"))]
+                mempool_monitor_service,
             }),
         };
 
@@ -259,6 +269,10 @@ impl Runtime {
             pub fn block_dag_monitor_service(&self) -> &Arc<BlockDagMonitorService> {
                 &self.inner.block_dag_monitor_service
             }
+
+            pub fn mempool_monitor_service(&self) -> &Arc<MempoolMonitorService> {
+                &self.inner.mempool_monitor_service
+            }
         }
     }
 
@@ -1,6 +1,31 @@
 use super::*;
 use std::collections::hash_map::Entry;
 
+pub struct CoinGecko;
+
+#[async_trait]
+impl MarketDataProvider for CoinGecko {
+    fn name(&self) -> &'static str {
+        "CoinGecko"
+    }
+
+    async fn fetch_available_currencies(&self) -> Result<CurrencyDescriptorList> {
+        fetch_available_currencies().await
+    }
+
+    async fn fetch_market_price_list(&self, currencies: &[&str]) -> Result<MarketDataMap> {
+        fetch_market_price_list(currencies).await
+    }
+
+    fn supports_ohlc(&self) -> bool {
+        true
+    }
+
+    async fn fetch_ohlc(&self, currency: &str, range: OhlcRange) -> Result<Ohlc> {
+        fetch_ohlc(currency, range).await
+    }
+}
+
 // https://api.coingecko.com/api/v3/simple/price?ids=tondi&vs_currencies=usd%2Ccny&include_market_cap=true&include_24hr_vol=true&include_24hr_change=true
 // {
 //     "tondi": {
@@ -64,6 +89,30 @@ pub async fn fetch_market_price_list(currencies: &[&str]) -> Result<MarketDataMa
     Ok(market_data.into())
 }
 
+// https://api.coingecko.com/api/v3/coins/tondi/ohlc?vs_currency=usd&days=1
+// [
+//   [ 1709395200000, 0.137, 0.139, 0.136, 0.138 ],
+//   ...
+// ]
+
+pub async fn fetch_ohlc(currency: &str, range: OhlcRange) -> Result<Ohlc> {
+    let currency = currency.to_lowercase();
+    let days = range.days();
+    let url = format!("https://api.coingecko.com/api/v3/coins/tondi/ohlc?vs_currency={currency}&days={days}");
+    let candles = http::get_json::<Vec<[f64; 5]>>(url)
+        .await?
+        .into_iter()
+        .map(|[timestamp, open, high, low, close]| OhlcCandle {
+            timestamp,
+            open,
+            high,
+            low,
+            close,
+        })
+        .collect();
+    Ok(Ohlc::new(currency.as_str(), range, candles))
+}
+
 fn group_by_currency_prefix(data: &AHashMap<String, f64>) -> MarketDataMap {
     let mut grouped_data: MarketDataMap = AHashMap::new();
 
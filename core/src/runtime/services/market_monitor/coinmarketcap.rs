@@ -2,6 +2,23 @@ use super::*;
 use std::collections::hash_map::Entry;
 use workflow_http::get_json;
 
+pub struct CoinMarketCap;
+
+#[async_trait]
+impl MarketDataProvider for CoinMarketCap {
+    fn name(&self) -> &'static str {
+        "CoinMarketCap"
+    }
+
+    async fn fetch_available_currencies(&self) -> Result<CurrencyDescriptorList> {
+        fetch_available_currencies().await
+    }
+
+    async fn fetch_market_price_list(&self, currencies: &[&str]) -> Result<MarketDataMap> {
+        fetch_market_price_list(currencies).await
+    }
+}
+
 #[derive(Default, Serialize, Deserialize)]
 struct CoinGeckoSimplePrice {
     tondi: Option<AHashMap<String, f64>>,
@@ -0,0 +1,78 @@
+use super::*;
+
+// Custom price endpoint, e.g. an internal price oracle:
+// {
+//     "usd": { "price": 0.137395, "market_cap": 2954668910.04, "volume": 138844602.78, "change": 16.77 },
+//     "btc": { "price": 0.00000211 }
+// }
+
+#[derive(Default, Debug, Deserialize)]
+struct HttpJsonMarketData {
+    price: f64,
+    #[serde(default)]
+    market_cap: f64,
+    #[serde(default)]
+    volume: f64,
+    #[serde(default)]
+    change: f64,
+}
+
+/// Fetches prices from a user-configured URL returning a JSON
+/// object keyed by currency symbol.
+pub struct HttpJson {
+    url: String,
+}
+
+impl HttpJson {
+    pub fn new(url: &str) -> Self {
+        Self {
+            url: url.trim().to_string(),
+        }
+    }
+}
+
+fn market_data_map(data: AHashMap<String, HttpJsonMarketData>, currencies: &[&str]) -> MarketDataMap {
+    data.into_iter()
+        .map(|(symbol, data)| (symbol.to_lowercase(), data))
+        .filter(|(symbol, _)| currencies.iter().any(|currency| currency.eq_ignore_ascii_case(symbol)))
+        .map(|(symbol, data)| {
+            let market_data = MarketData {
+                price: data.price,
+                market_cap: data.market_cap,
+                volume: data.volume,
+                change: data.change,
+                ..MarketData::new(symbol.as_str())
+            };
+            (symbol, market_data)
+        })
+        .collect()
+}
+
+#[async_trait]
+impl MarketDataProvider for HttpJson {
+    fn name(&self) -> &'static str {
+        "HTTP JSON"
+    }
+
+    async fn fetch_market_price_list(&self, currencies: &[&str]) -> Result<MarketDataMap> {
+        let data = http::get_json::<AHashMap<String, HttpJsonMarketData>>(self.url.as_str()).await?;
+        Ok(market_data_map(data, currencies))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_http_json_market_data() {
+        let json = r#"{ "USD": { "price": 0.25, "change": -1.5 }, "eur": { "price": 0.23 } }"#;
+        let data = serde_json::from_str::<AHashMap<String, HttpJsonMarketData>>(json).unwrap();
+        let map = market_data_map(data, &["usd", "btc"]);
+        assert_eq!(map.len(), 1);
+        let usd = map.get("usd").unwrap();
+        assert_eq!(usd.price, 0.25);
+        assert_eq!(usd.change, -1.5);
+        assert_eq!(usd.market_cap, 0.0);
+    }
+}
@@ -0,0 +1,58 @@
+use super::*;
+
+/// Deterministic provider for unit tests; counts requests
+/// so that caching can be verified.
+#[derive(Default)]
+pub struct Mock {
+    pub price: f64,
+    pub price_requests: AtomicUsize,
+    pub ohlc_requests: AtomicUsize,
+}
+
+impl Mock {
+    pub fn new(price: f64) -> Self {
+        Self {
+            price,
+            ..Default::default()
+        }
+    }
+}
+
+#[async_trait]
+impl MarketDataProvider for Mock {
+    fn name(&self) -> &'static str {
+        "Mock"
+    }
+
+    async fn fetch_market_price_list(&self, currencies: &[&str]) -> Result<MarketDataMap> {
+        self.price_requests.fetch_add(1, Ordering::SeqCst);
+        Ok(currencies
+            .iter()
+            .map(|symbol| {
+                let market_data = MarketData {
+                    price: self.price,
+                    ..MarketData::new(symbol)
+                };
+                (symbol.to_string(), market_data)
+            })
+            .collect())
+    }
+
+    fn supports_ohlc(&self) -> bool {
+        true
+    }
+
+    async fn fetch_ohlc(&self, currency: &str, range: OhlcRange) -> Result<Ohlc> {
+        self.ohlc_requests.fetch_add(1, Ordering::SeqCst);
+        let candles = (0..range.days())
+            .map(|day| OhlcCandle {
+                timestamp: (day * 86_400_000) as f64,
+                open: self.price,
+                high: self.price,
+                low: self.price,
+                close: self.price,
+            })
+            .collect();
+        Ok(Ohlc::new(currency, range, candles))
+    }
+}
@@ -1,42 +1,48 @@
 use crate::imports::*;
 use crate::market::*;
+use crate::settings::MARKET_MONITOR_MIN_INTERVAL;
 
 mod coingecko;
 mod coinmarketcap;
+mod fixed_rate;
+mod http_json;
+#[cfg(test)]
+mod mock;
 
-pub const POLLING_INTERVAL_SECONDS: u64 = 60;
-
-#[derive(Default, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "kebab-case")]
-pub enum MarketDataProvider {
-    #[default]
-    CoinGecko,
-    CoinMarketCap,
-}
+/// Source of market prices and price history
+#[async_trait]
+pub trait MarketDataProvider: Send + Sync {
+    fn name(&self) -> &'static str;
 
-impl MarketDataProvider {
-    #[allow(dead_code)]
     async fn fetch_available_currencies(&self) -> Result<CurrencyDescriptorList> {
-        match self {
-            Self::CoinGecko => coingecko::fetch_available_currencies().await,
-            Self::CoinMarketCap => coinmarketcap::fetch_available_currencies().await,
-        }
+        Err(Error::custom(format!("{} does not provide a currency list", self.name())))
     }
 
-    async fn fetch_market_price_list(&self, currencies: &[&str]) -> Result<MarketDataMap> {
-        match self {
-            Self::CoinGecko => coingecko::fetch_market_price_list(currencies).await,
-            Self::CoinMarketCap => coinmarketcap::fetch_market_price_list(currencies).await,
+    async fn fetch_market_price_list(&self, currencies: &[&str]) -> Result<MarketDataMap>;
+
+    /// Providers without price history are not polled for OHLC data
+    fn supports_ohlc(&self) -> bool {
+        false
+    }
+
+    async fn fetch_ohlc(&self, _currency: &str, _range: OhlcRange) -> Result<Ohlc> {
+        Err(Error::custom(format!("{} does not provide OHLC data", self.name())))
+    }
+}
+
+impl From<&MarketDataProviderKind> for Arc<dyn MarketDataProvider> {
+    fn from(kind: &MarketDataProviderKind) -> Self {
+        match kind {
+            MarketDataProviderKind::CoinGecko => Arc::new(coingecko::CoinGecko),
+            MarketDataProviderKind::CoinMarketCap => Arc::new(coinmarketcap::CoinMarketCap),
+            MarketDataProviderKind::HttpJson { url } => Arc::new(http_json::HttpJson::new(url)),
+            MarketDataProviderKind::FixedRate { rates } => Arc::new(fixed_rate::FixedRate::new(rates)),
         }
     }
 }
 
-#[derive(Default, Serialize, Deserialize)]
-#[allow(dead_code)]
-struct MarketMonitorSettings {
-    enabled: bool,
-    provider: MarketDataProvider,
-    currencies: Vec<String>,
+fn polling_interval(settings: &MarketMonitorSettings) -> Duration {
+    Duration::from_secs(settings.interval.max(MARKET_MONITOR_MIN_INTERVAL))
 }
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -51,6 +57,8 @@ pub type CurrencyDescriptorList = Vec<CurrencyDescriptor>;
 pub enum MarketMonitorEvents {
     Enable,
     Disable,
+    OhlcRange(OhlcRange),
+    Settings(MarketMonitorSettings),
     Exit,
 }
 
@@ -60,27 +68,28 @@ pub struct MarketMonitorService {
     pub task_ctl: Channel<()>,
     pub is_enabled: AtomicBool,
     pub currencies: Mutex<Option<Vec<String>>>,
-    pub provider: Mutex<MarketDataProvider>,
+    pub provider: Mutex<Arc<dyn MarketDataProvider>>,
+    pub interval: Mutex<Duration>,
     pub available_currencies: Mutex<Option<Vec<CurrencyDescriptor>>>,
     pub market_price_list: Mutex<Option<Arc<MarketDataMap>>>,
+    pub ohlc_range: Mutex<OhlcRange>,
+    pub ohlc_cache: Mutex<AHashMap<OhlcRange, (Instant, Arc<OhlcMap>)>>,
 }
 
 impl MarketMonitorService {
     pub fn new(application_events: ApplicationEventsChannel, settings: &Settings) -> Self {
-        let currencies = ["usd", "btc"]
-            .into_iter()
-            .map(String::from)
-            .collect::<Vec<_>>();
-
         Self {
             application_events,
             service_events: Channel::unbounded(),
             task_ctl: Channel::oneshot(),
             is_enabled: AtomicBool::new(settings.market_monitor),
-            provider: Mutex::new(MarketDataProvider::default()),
-            currencies: Mutex::new(Some(currencies)),
+            provider: Mutex::new((&settings.market.provider).into()),
+            interval: Mutex::new(polling_interval(&settings.market)),
+            currencies: Mutex::new(Some(settings.market.currencies.clone())),
             available_currencies: Mutex::new(None),
             market_price_list: Mutex::new(None),
+            ohlc_range: Mutex::new(OhlcRange::default()),
+            ohlc_cache: Mutex::new(AHashMap::new()),
         }
     }
 
@@ -88,10 +97,30 @@ impl MarketMonitorService {
         self.currencies.lock().unwrap().clone()
     }
 
-    pub fn provider(&self) -> MarketDataProvider {
+    pub fn provider(&self) -> Arc<dyn MarketDataProvider> {
         self.provider.lock().unwrap().clone()
     }
 
+    pub fn interval(&self) -> Duration {
+        *self.interval.lock().unwrap()
+    }
+
+    /// Apply provider, interval and currency changes; cached
+    /// data of the previous provider is discarded.
+    pub fn update_settings(&self, settings: &MarketMonitorSettings) {
+        self.service_events
+            .try_send(MarketMonitorEvents::Settings(settings.clone()))
+            .unwrap();
+    }
+
+    fn apply_settings(&self, settings: &MarketMonitorSettings) {
+        *self.provider.lock().unwrap() = (&settings.provider).into();
+        *self.interval.lock().unwrap() = polling_interval(settings);
+        self.currencies.lock().unwrap().replace(settings.currencies.clone());
+        self.available_currencies.lock().unwrap().take();
+        self.ohlc_cache.lock().unwrap().clear();
+    }
+
     pub fn enable(&self, enable: bool) {
         if enable {
             self.service_events
@@ -104,6 +133,17 @@ impl MarketMonitorService {
         }
     }
 
+    pub fn ohlc_range(&self) -> OhlcRange {
+        *self.ohlc_range.lock().unwrap()
+    }
+
+    /// Select the range of OHLC candles published to the application
+    pub fn select_ohlc_range(&self, range: OhlcRange) {
+        self.service_events
+            .try_send(MarketMonitorEvents::OhlcRange(range))
+            .unwrap();
+    }
+
     #[allow(dead_code)]
     async fn update_available_currencies(&self) -> Result<()> {
         let available_currencies = self.provider().fetch_available_currencies().await?;
@@ -130,6 +170,77 @@ impl MarketMonitorService {
         }
         Ok(())
     }
+
+    /// OHLC candles of `range`, fetched from the provider only
+    /// if the cached copy has expired.
+    async fn ohlc(&self, range: OhlcRange) -> Result<Option<Arc<OhlcMap>>> {
+        let provider = self.provider();
+        if !provider.supports_ohlc() {
+            return Ok(None);
+        }
+
+        let cached = self
+            .ohlc_cache
+            .lock()
+            .unwrap()
+            .get(&range)
+            .filter(|(timestamp, _)| timestamp.elapsed() < range.cache_duration())
+            .map(|(_, ohlc)| ohlc.clone());
+
+        if cached.is_some() {
+            return Ok(cached);
+        }
+
+        let Some(currencies) = self.currencies() else {
+            return Ok(None);
+        };
+
+        let mut ohlc = OhlcMap::new();
+        for currency in currencies.iter() {
+            match provider.fetch_ohlc(currency, range).await {
+                Ok(candles) => {
+                    ohlc.insert(currency.clone(), candles);
+                }
+                Err(err) => {
+                    log_warn!("market monitor: unable to fetch OHLC data for {currency}: {err}");
+                }
+            }
+        }
+
+        if ohlc.is_empty() {
+            return Ok(None);
+        }
+
+        let ohlc = Arc::new(ohlc);
+        self.ohlc_cache
+            .lock()
+            .unwrap()
+            .insert(range, (Instant::now(), ohlc.clone()));
+        Ok(Some(ohlc))
+    }
+
+    /// Publish OHLC candles of the selected range along with
+    /// the long range history used to value past transactions
+    async fn update_ohlc(&self) -> Result<()> {
+        let range = self.ohlc_range();
+
+        // the range may have changed while fetching
+        if let Some(ohlc) = self.ohlc(range).await?.filter(|_| range == self.ohlc_range()) {
+            self.application_events
+                .sender
+                .try_send(Events::Market(MarketUpdate::Ohlc(ohlc)))
+                .unwrap();
+        }
+
+        if let Some(history) = self.ohlc(OhlcRange::Year).await? {
+            self.application_events
+                .sender
+                .try_send(Events::Market(MarketUpdate::History(history)))
+                .unwrap();
+        }
+
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -141,13 +252,13 @@ impl Service for MarketMonitorService {
     async fn spawn(self: Arc<Self>) -> Result<()> {
         let this = self.clone();
         let _application_events_sender = self.application_events.sender.clone();
-        let interval = task::interval(Duration::from_secs(POLLING_INTERVAL_SECONDS));
-        pin_mut!(interval);
+        let mut interval = Box::pin(task::interval(self.interval()));
 
         loop {
             select! {
                 _ = interval.next().fuse() => {
                     this.update_market_price_list().await?;
+                    this.update_ohlc().await?;
                 },
 
                 msg = this.as_ref().service_events.receiver.recv().fuse() => {
@@ -157,6 +268,21 @@ impl Service for MarketMonitorService {
                                 if !this.is_enabled.load(Ordering::SeqCst) {
                                     this.is_enabled.store(true, Ordering::SeqCst);
                                     this.update_market_price_list().await?;
+                                    this.update_ohlc().await?;
+                                }
+                            }
+                            MarketMonitorEvents::Settings(settings) => {
+                                this.apply_settings(&settings);
+                                interval = Box::pin(task::interval(this.interval()));
+                                if this.is_enabled.load(Ordering::SeqCst) {
+                                    this.update_market_price_list().await?;
+                                    this.update_ohlc().await?;
+                                }
+                            }
+                            MarketMonitorEvents::OhlcRange(range) => {
+                                *this.ohlc_range.lock().unwrap() = range;
+                                if this.is_enabled.load(Ordering::SeqCst) {
+                                    this.update_ohlc().await?;
                                 }
                             }
                             MarketMonitorEvents::Disable => {
@@ -190,3 +316,30 @@ impl Service for MarketMonitorService {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_market_data_provider() {
+        let service = MarketMonitorService::new(ApplicationEventsChannel::unbounded(), &Settings::default());
+        let mock = Arc::new(mock::Mock::new(0.5));
+        *service.provider.lock().unwrap() = mock.clone();
+
+        futures::executor::block_on(async {
+            let prices = service.provider().fetch_market_price_list(&["usd"]).await.unwrap();
+            assert_eq!(prices.get("usd").map(|data| data.price), Some(0.5));
+
+            // OHLC candles are served from the cache until it expires
+            for _ in 0..3 {
+                let ohlc = service.ohlc(OhlcRange::Week).await.unwrap().unwrap();
+                assert_eq!(ohlc.get("usd").unwrap().candles.len(), 7);
+            }
+            assert_eq!(mock.ohlc_requests.load(Ordering::SeqCst), Settings::default().market.currencies.len());
+
+            service.apply_settings(&Settings::default().market);
+            assert!(service.ohlc_cache.lock().unwrap().is_empty());
+        });
+    }
+}
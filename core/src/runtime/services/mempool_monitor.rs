@@ -0,0 +1,233 @@
+use crate::imports::*;
+use tondi_consensus_core::mass::transaction_estimated_serialized_size;
+use tondi_consensus_core::tx::Transaction as ConsensusTransaction;
+use tondi_rpc_core::RpcMempoolEntry;
+
+pub const MEMPOOL_POLLING_INTERVAL_SECONDS: u64 = 2;
+
+/// Mempool entry summary, derived once when the
+/// entries are fetched from the node.
+#[derive(Debug, Clone)]
+pub struct MempoolEntry {
+    pub id: Option<TransactionId>,
+    pub fee: u64,
+    pub mass: u64,
+    /// Estimated serialized transaction size in bytes
+    pub size: Option<u64>,
+    pub is_orphan: bool,
+}
+
+impl MempoolEntry {
+    pub fn fee_rate(&self) -> f64 {
+        if self.mass == 0 {
+            0.0
+        } else {
+            self.fee as f64 / self.mass as f64
+        }
+    }
+}
+
+impl From<&RpcMempoolEntry> for MempoolEntry {
+    fn from(entry: &RpcMempoolEntry) -> Self {
+        let verbose_data = entry.transaction.verbose_data.as_ref();
+        let transaction = ConsensusTransaction::try_from(entry.transaction.clone()).ok();
+        let id = verbose_data
+            .map(|verbose_data| verbose_data.transaction_id)
+            .or_else(|| transaction.as_ref().map(|tx| tx.id()));
+        let mass = if entry.transaction.mass > 0 {
+            entry.transaction.mass
+        } else {
+            verbose_data
+                .map(|verbose_data| verbose_data.compute_mass)
+                .unwrap_or_default()
+        };
+        let size = transaction.as_ref().map(transaction_estimated_serialized_size);
+
+        Self {
+            id,
+            fee: entry.fee,
+            mass,
+            size,
+            is_orphan: entry.is_orphan,
+        }
+    }
+}
+
+pub enum MempoolMonitorEvents {
+    Enable,
+    Disable,
+    Filter(Option<Vec<Address>>),
+    Exit,
+}
+
+pub struct MempoolMonitorService {
+    pub application_events: ApplicationEventsChannel,
+    pub service_events: Channel<MempoolMonitorEvents>,
+    pub task_ctl: Channel<()>,
+    pub rpc_api: Mutex<Option<Arc<dyn RpcApi>>>,
+    pub entries: Mutex<Option<Arc<Vec<MempoolEntry>>>>,
+    pub filter: Mutex<Option<Vec<Address>>>,
+    pub is_enabled: Arc<AtomicBool>,
+}
+
+impl MempoolMonitorService {
+    pub fn new(application_events: ApplicationEventsChannel, _settings: &Settings) -> Self {
+        Self {
+            application_events,
+            service_events: Channel::unbounded(),
+            task_ctl: Channel::oneshot(),
+            rpc_api: Mutex::new(None),
+            entries: Mutex::new(None),
+            filter: Mutex::new(None),
+            is_enabled: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    pub fn rpc_api(&self) -> Option<Arc<dyn RpcApi>> {
+        self.rpc_api.lock().unwrap().clone()
+    }
+
+    pub fn entries(&self) -> Option<Arc<Vec<MempoolEntry>>> {
+        self.entries.lock().unwrap().clone()
+    }
+
+    pub fn enable(&self) {
+        self.service_events
+            .sender
+            .try_send(MempoolMonitorEvents::Enable)
+            .unwrap();
+    }
+
+    pub fn disable(&self) {
+        self.service_events
+            .sender
+            .try_send(MempoolMonitorEvents::Disable)
+            .unwrap();
+    }
+
+    /// Restrict the monitored entries to transactions sending
+    /// to or receiving from the given addresses (`None` lists
+    /// the entire mempool).
+    pub fn set_filter(&self, addresses: Option<Vec<Address>>) {
+        self.service_events
+            .sender
+            .try_send(MempoolMonitorEvents::Filter(addresses))
+            .unwrap();
+    }
+
+    async fn fetch(&self) -> Result<()> {
+        let Some(rpc_api) = self.rpc_api() else {
+            return Ok(());
+        };
+
+        let filter = self.filter.lock().unwrap().clone();
+        let entries = if let Some(addresses) = filter {
+            let entries_by_address = rpc_api
+                .get_mempool_entries_by_addresses(
+                    addresses.into_iter().map(|address| address.into()).collect(),
+                    true,
+                    false,
+                )
+                .await?;
+
+            // a transaction can appear under multiple addresses
+            // and as both sending and receiving, so de-duplicate
+            let mut seen = AHashSet::new();
+            entries_by_address
+                .into_iter()
+                .flat_map(|entry| entry.sending.into_iter().chain(entry.receiving))
+                .filter(|entry| {
+                    entry
+                        .transaction
+                        .verbose_data
+                        .as_ref()
+                        .map(|verbose_data| seen.insert(verbose_data.transaction_id))
+                        .unwrap_or(true)
+                })
+                .collect::<Vec<_>>()
+        } else {
+            rpc_api.get_mempool_entries(true, false).await?
+        };
+
+        let entries = entries.iter().map(MempoolEntry::from).collect::<Vec<_>>();
+        self.entries.lock().unwrap().replace(Arc::new(entries));
+        runtime().request_repaint();
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Service for MempoolMonitorService {
+    fn name(&self) -> &'static str {
+        "mempool-monitor"
+    }
+
+    async fn attach_rpc(self: Arc<Self>, rpc_api: &Arc<dyn RpcApi>) -> Result<()> {
+        self.rpc_api.lock().unwrap().replace(rpc_api.clone());
+        Ok(())
+    }
+
+    async fn detach_rpc(self: Arc<Self>) -> Result<()> {
+        self.rpc_api.lock().unwrap().take();
+        self.entries.lock().unwrap().take();
+
+        Ok(())
+    }
+
+    async fn spawn(self: Arc<Self>) -> Result<()> {
+        let interval = task::interval(Duration::from_secs(MEMPOOL_POLLING_INTERVAL_SECONDS));
+        pin_mut!(interval);
+
+        loop {
+            select! {
+                _ = interval.next().fuse() => {
+                    if !self.is_enabled.load(Ordering::Relaxed) {
+                        continue;
+                    }
+
+                    if let Err(err) = self.fetch().await {
+                        log_warn!("MempoolMonitor: unable to fetch mempool entries: {}", err);
+                    }
+                },
+                msg = self.as_ref().service_events.receiver.recv().fuse() => {
+                    if let Ok(event) = msg {
+                        match event {
+                            MempoolMonitorEvents::Enable => {
+                                self.is_enabled.store(true, Ordering::Relaxed);
+                            }
+                            MempoolMonitorEvents::Disable => {
+                                self.is_enabled.store(false, Ordering::Relaxed);
+                                self.entries.lock().unwrap().take();
+                            }
+                            MempoolMonitorEvents::Filter(addresses) => {
+                                *self.filter.lock().unwrap() = addresses;
+                                self.entries.lock().unwrap().take();
+                            }
+                            MempoolMonitorEvents::Exit => {
+                                break;
+                            }
+                        }
+                    } else {
+                        break;
+                    }
+                }
+            }
+        }
+
+        self.task_ctl.send(()).await.unwrap();
+        Ok(())
+    }
+
+    fn terminate(self: Arc<Self>) {
+        self.service_events
+            .sender
+            .try_send(MempoolMonitorEvents::Exit)
+            .unwrap();
+    }
+
+    async fn join(self: Arc<Self>) -> Result<()> {
+        self.task_ctl.recv().await.unwrap();
+        Ok(())
+    }
+}
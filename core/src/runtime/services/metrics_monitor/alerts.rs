@@ -0,0 +1,167 @@
+use crate::imports::*;
+use tondi_metrics_core::Metric;
+
+#[derive(Default)]
+struct AlertState {
+    /// Timestamp (unix seconds) since which the condition holds
+    since: Option<u64>,
+    last_value: Option<f64>,
+    raised: bool,
+}
+
+/// Evaluates [`MetricAlertRule`]s against incoming metric values,
+/// tracking how long each rule condition has been satisfied.
+#[derive(Default)]
+pub struct MetricAlerts {
+    rules: Vec<(MetricAlertRule, AlertState)>,
+}
+
+impl MetricAlerts {
+    pub fn new(rules: &[MetricAlertRule]) -> Self {
+        let mut alerts = Self::default();
+        alerts.update_rules(rules);
+        alerts
+    }
+
+    /// Replace the rules, keeping the evaluation state of rules that did not
+    /// change so that their pending or raised alerts are not reset.
+    pub fn update_rules(&mut self, rules: &[MetricAlertRule]) {
+        let mut previous = std::mem::take(&mut self.rules);
+        self.rules = rules
+            .iter()
+            .filter(|rule| rule.enable)
+            .map(|rule| {
+                let state = previous
+                    .iter()
+                    .position(|(existing, _)| existing == rule)
+                    .map(|index| previous.swap_remove(index).1)
+                    .unwrap_or_default();
+                (rule.clone(), state)
+            })
+            .collect();
+    }
+
+    /// Evaluate all rules at `timestamp` (unix seconds), returning
+    /// messages for rules whose condition has just been met.
+    pub fn evaluate(&mut self, timestamp: u64, values: &HashMap<Metric, f64>) -> Vec<String> {
+        let mut messages = Vec::new();
+
+        for (rule, state) in self.rules.iter_mut() {
+            let Some(value) = values.get(&rule.metric).copied().filter(|value| value.is_finite()) else {
+                continue;
+            };
+
+            let holds = match rule.condition {
+                MetricAlertCondition::Above => value > rule.threshold,
+                MetricAlertCondition::Below => value < rule.threshold,
+                MetricAlertCondition::Unchanged => state.last_value == Some(value),
+            };
+            state.last_value = Some(value);
+
+            if !holds {
+                // an unchanged value is measured from the first sample carrying it
+                state.since = matches!(rule.condition, MetricAlertCondition::Unchanged).then_some(timestamp);
+                state.raised = false;
+                continue;
+            }
+
+            let since = *state.since.get_or_insert(timestamp);
+            if !state.raised && timestamp.saturating_sub(since) >= rule.duration {
+                state.raised = true;
+                messages.push(Self::message(rule, value));
+            }
+        }
+
+        messages
+    }
+
+    fn message(rule: &MetricAlertRule, value: f64) -> String {
+        let metric = i18n(rule.metric.title().0);
+        let value = rule.metric.format(value, true, false);
+        let duration = rule.duration.to_string();
+        if rule.condition.has_threshold() {
+            i18n_args(
+                "{metric} is {value}, {condition} {threshold} for {duration} sec",
+                &[
+                    ("metric", metric),
+                    ("value", value.as_str()),
+                    ("condition", rule.condition.to_string().to_lowercase().as_str()),
+                    ("threshold", rule.metric.format(rule.threshold, true, false).as_str()),
+                    ("duration", duration.as_str()),
+                ],
+            )
+        } else {
+            i18n_args(
+                "{metric} has not changed from {value} for {duration} sec",
+                &[("metric", metric), ("value", value.as_str()), ("duration", duration.as_str())],
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metric_alerts() {
+        let mut alerts = MetricAlerts::new(&[
+            MetricAlertRule {
+                enable: true,
+                metric: Metric::NodeCpuUsage,
+                condition: MetricAlertCondition::Above,
+                threshold: 90.0,
+                duration: 5,
+            },
+            MetricAlertRule {
+                enable: true,
+                metric: Metric::NetworkVirtualDaaScore,
+                condition: MetricAlertCondition::Unchanged,
+                threshold: 0.0,
+                duration: 3,
+            },
+        ]);
+
+        let mut raised = 0;
+        for second in 0..10u64 {
+            let cpu = if second < 2 { 50.0 } else { 95.0 };
+            let values = HashMap::from([(Metric::NodeCpuUsage, cpu), (Metric::NetworkVirtualDaaScore, 100.0)]);
+            raised += alerts.evaluate(1000 + second, &values).len();
+            match second {
+                // daa score unchanged since second 0
+                3 => assert_eq!(raised, 1),
+                // cpu above 90 since second 2
+                7 => assert_eq!(raised, 2),
+                _ => {}
+            }
+        }
+        // alerts are raised once while the condition holds
+        assert_eq!(raised, 2);
+    }
+
+    #[test]
+    fn test_metric_alerts_update_keeps_state() {
+        let cpu = MetricAlertRule {
+            enable: true,
+            metric: Metric::NodeCpuUsage,
+            condition: MetricAlertCondition::Above,
+            threshold: 90.0,
+            duration: 0,
+        };
+        let mut alerts = MetricAlerts::new(&[cpu.clone()]);
+        let values = HashMap::from([(Metric::NodeCpuUsage, 95.0)]);
+        assert_eq!(alerts.evaluate(1000, &values).len(), 1);
+
+        // adding an unrelated rule does not raise the existing alert again
+        let memory = MetricAlertRule {
+            metric: Metric::NodeResidentSetSizeBytes,
+            ..cpu.clone()
+        };
+        alerts.update_rules(&[cpu.clone(), memory]);
+        assert_eq!(alerts.evaluate(1001, &values).len(), 0);
+
+        // a modified rule starts over
+        alerts.update_rules(&[MetricAlertRule { threshold: 80.0, ..cpu }]);
+        assert_eq!(alerts.evaluate(1002, &values).len(), 1);
+    }
+}
@@ -0,0 +1,89 @@
+use crate::imports::*;
+use chrono::DateTime;
+use std::collections::BTreeMap;
+use tondi_metrics_core::Metric;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum MetricsExportFormat {
+    Csv,
+    Json,
+}
+
+impl MetricsExportFormat {
+    pub fn iter() -> impl Iterator<Item = MetricsExportFormat> {
+        [MetricsExportFormat::Csv, MetricsExportFormat::Json].into_iter()
+    }
+
+    pub fn describe(&self) -> &'static str {
+        match self {
+            MetricsExportFormat::Csv => "CSV",
+            MetricsExportFormat::Json => "JSON",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            MetricsExportFormat::Csv => "csv",
+            MetricsExportFormat::Json => "json",
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Sample {
+    timestamp: u64,
+    value: f64,
+}
+
+/// Serialize metric series (as produced by
+/// [`MetricsService::metrics_range`](super::MetricsService::metrics_range))
+/// into the given export format.
+pub fn export_metrics(series: &[(Metric, Vec<PlotPoint>)], format: MetricsExportFormat) -> Result<String> {
+    match format {
+        MetricsExportFormat::Csv => {
+            let mut csv = String::from("timestamp,datetime,metric,value\n");
+            for (metric, points) in series {
+                for point in points {
+                    let datetime = DateTime::<chrono::Utc>::from_timestamp_millis(point.x as i64)
+                        .map(|datetime| datetime.to_rfc3339())
+                        .unwrap_or_default();
+                    csv.push_str(&format!("{},{},{},{}\n", point.x as u64, datetime, metric.as_str(), point.y));
+                }
+            }
+            Ok(csv)
+        }
+        MetricsExportFormat::Json => {
+            let series = series
+                .iter()
+                .map(|(metric, points)| {
+                    let samples = points
+                        .iter()
+                        .map(|point| Sample {
+                            timestamp: point.x as u64,
+                            value: point.y,
+                        })
+                        .collect::<Vec<_>>();
+                    (metric.as_str(), samples)
+                })
+                .collect::<BTreeMap<_, _>>();
+            Ok(serde_json::to_string_pretty(&series)?)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_metrics_csv() {
+        let series = vec![(Metric::NodeCpuUsage, vec![PlotPoint::new(1_700_000_000_000.0, 12.5)])];
+        let csv = export_metrics(&series, MetricsExportFormat::Csv).unwrap();
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some("timestamp,datetime,metric,value"));
+        assert_eq!(
+            lines.next(),
+            Some(format!("1700000000000,2023-11-14T22:13:20+00:00,{},12.5", Metric::NodeCpuUsage.as_str()).as_str())
+        );
+    }
+}
@@ -0,0 +1,449 @@
+use crate::imports::*;
+use borsh::{BorshDeserialize, BorshSerialize};
+use std::collections::VecDeque;
+use tondi_metrics_core::Metric;
+use tondi_wallet_core::storage::local::storage::Storage;
+
+const METRICS_HISTORY_VERSION: u16 = 2;
+
+/// The log is compacted once it holds this many times
+/// more records than the retained history.
+const METRICS_HISTORY_COMPACTION_FACTOR: usize = 2;
+
+/// Downsampling tier of the metrics history.  Each tier holds
+/// averaged samples at a fixed interval for a limited retention period.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Tier {
+    Seconds,
+    Minutes,
+    Hours,
+}
+
+impl Tier {
+    pub const ALL: [Tier; 3] = [Tier::Seconds, Tier::Minutes, Tier::Hours];
+
+    /// Sample interval in seconds
+    pub fn interval(&self) -> u64 {
+        match self {
+            Tier::Seconds => 1,
+            Tier::Minutes => 60,
+            Tier::Hours => 60 * 60,
+        }
+    }
+
+    /// Retention period in seconds
+    pub fn retention(&self) -> u64 {
+        match self {
+            Tier::Seconds => 60 * 60,
+            Tier::Minutes => 60 * 60 * 24 * 7,
+            Tier::Hours => 60 * 60 * 24 * 30,
+        }
+    }
+
+    fn index(&self) -> usize {
+        *self as usize
+    }
+
+    /// The finest tier that still covers `seconds` into the past.
+    pub fn for_range(seconds: u64) -> Tier {
+        Tier::ALL
+            .into_iter()
+            .find(|tier| tier.retention() >= seconds)
+            .unwrap_or(Tier::Hours)
+    }
+}
+
+/// Longest range (in seconds) available from the metrics history.
+pub const MAX_METRICS_HISTORY_RANGE: u64 = 60 * 60 * 24 * 30;
+
+/// Running average of samples falling into the current bucket of a tier.
+#[derive(Default, Clone, Copy)]
+struct Bucket {
+    start: u64,
+    sum: f64,
+    count: u32,
+}
+
+impl Bucket {
+    fn average(&self) -> f32 {
+        (self.sum / self.count.max(1) as f64) as f32
+    }
+}
+
+type Series = VecDeque<(u32, f32)>;
+
+/// A single completed bucket of a tier, as appended to the history log.
+#[derive(Clone, BorshSerialize, BorshDeserialize)]
+struct LogRecord {
+    tier: u8,
+    metric: String,
+    timestamp: u32,
+    value: f32,
+}
+
+/// Pending write of the metrics history log, produced while holding
+/// the history lock and committed to disk without it.
+pub enum HistoryWrite {
+    /// Records to append to the existing log
+    Append { network: Network, data: Vec<u8> },
+    /// Complete log replacing the existing one
+    Compact { network: Network, data: Vec<u8> },
+}
+
+impl HistoryWrite {
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn commit(self) -> Result<()> {
+        use std::io::Write;
+
+        match self {
+            HistoryWrite::Append { network, data } => {
+                let storage = MetricsHistory::storage(network)?;
+                storage.ensure_dir_sync()?;
+                let filename = storage.filename();
+                let is_new = std::fs::metadata(&filename).map(|metadata| metadata.len() == 0).unwrap_or(true);
+                let mut file = std::fs::OpenOptions::new().create(true).append(true).open(&filename)?;
+                if is_new {
+                    file.write_all(&borsh::to_vec(&METRICS_HISTORY_VERSION)?)?;
+                }
+                file.write_all(&data)?;
+            }
+            HistoryWrite::Compact { network, data } => {
+                let storage = MetricsHistory::storage(network)?;
+                storage.ensure_dir_sync()?;
+                let filename = storage.filename();
+                let temp = filename.with_extension("tmp");
+                std::fs::write(&temp, data)?;
+                std::fs::rename(&temp, &filename)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn commit(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Rolling per-network store of metric samples, persisted in the
+/// application data folder as `tondi-ng.metrics.<network>`.
+///
+/// Completed samples are appended to a log that is rewritten
+/// (compacted) only once it has grown well beyond the retained history.
+pub struct MetricsHistory {
+    network: Network,
+    tiers: [HashMap<Metric, Series>; 3],
+    buckets: [HashMap<Metric, Bucket>; 3],
+    /// Samples completed since the last write
+    pending: Vec<LogRecord>,
+    /// Number of records currently held by the log on disk
+    logged: usize,
+    /// The log on disk is unreadable or outdated and must be rewritten
+    needs_compaction: bool,
+    last_store: Instant,
+}
+
+impl MetricsHistory {
+    pub fn new(network: Network) -> Self {
+        Self {
+            network,
+            tiers: Default::default(),
+            buckets: Default::default(),
+            pending: Vec::new(),
+            logged: 0,
+            needs_compaction: false,
+            last_store: Instant::now(),
+        }
+    }
+
+    pub fn network(&self) -> Network {
+        self.network
+    }
+
+    fn storage(network: Network) -> Result<Storage> {
+        Ok(Storage::try_new(&format!("tondi-ng.metrics.{network}"))?)
+    }
+
+    /// Load the history of the given network, returning an empty
+    /// history if none has been stored or if it can not be read.
+    pub fn load(network: Network) -> Self {
+        let mut history = Self::new(network);
+        match history.read() {
+            Ok(()) => history.prune(unixtime_as_millis_f64() as u64 / 1000),
+            Err(err) => {
+                log_warn!("Unable to load metrics history for {network}: {err}");
+                history.needs_compaction = true;
+            }
+        }
+        history
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn read(&mut self) -> Result<()> {
+        let storage = Self::storage(self.network)?;
+        if !storage.filename().exists() {
+            return Ok(());
+        }
+
+        let data = std::fs::read(storage.filename())?;
+        let mut reader = data.as_slice();
+        if u16::deserialize(&mut reader).ok() != Some(METRICS_HISTORY_VERSION) {
+            self.needs_compaction = true;
+            return Ok(());
+        }
+
+        let metrics = Metric::into_iter().map(|metric| (metric.as_str(), metric)).collect::<HashMap<_, _>>();
+        while !reader.is_empty() {
+            // a partially written trailing record is dropped by the next compaction
+            let Ok(record) = LogRecord::deserialize(&mut reader) else {
+                self.needs_compaction = true;
+                break;
+            };
+            self.logged += 1;
+
+            let (Some(tier), Some(metric)) = (self.tiers.get_mut(record.tier as usize), metrics.get(record.metric.as_str())) else {
+                continue;
+            };
+            let series = tier.entry(*metric).or_default();
+            match series.back_mut() {
+                // a bucket flushed on exit is superseded by its completed value
+                Some(last) if last.0 == record.timestamp => last.1 = record.value,
+                // records may be duplicated if an append raced a compaction
+                Some(last) if last.0 > record.timestamp => {}
+                _ => series.push_back((record.timestamp, record.value)),
+            }
+        }
+
+        Ok(())
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn read(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn retained(&self) -> usize {
+        self.tiers.iter().flat_map(|tier| tier.values()).map(|series| series.len()).sum()
+    }
+
+    /// Take the pending samples as a write to the history log, or the
+    /// complete retained history if the log is due for compaction.
+    pub fn take_write(&mut self) -> Result<Option<HistoryWrite>> {
+        self.last_store = Instant::now();
+
+        let retained = self.retained();
+        let network = self.network;
+        if self.needs_compaction || self.logged + self.pending.len() > retained.max(1) * METRICS_HISTORY_COMPACTION_FACTOR {
+            self.pending.clear();
+            let mut data = borsh::to_vec(&METRICS_HISTORY_VERSION)?;
+            for (index, tier) in self.tiers.iter().enumerate() {
+                for (metric, series) in tier.iter() {
+                    for (timestamp, value) in series.iter() {
+                        let record = LogRecord {
+                            tier: index as u8,
+                            metric: metric.as_str().to_string(),
+                            timestamp: *timestamp,
+                            value: *value,
+                        };
+                        record.serialize(&mut data)?;
+                    }
+                }
+            }
+            self.logged = retained;
+            self.needs_compaction = false;
+            Ok(Some(HistoryWrite::Compact { network, data }))
+        } else if self.pending.is_empty() {
+            Ok(None)
+        } else {
+            let mut data = Vec::new();
+            self.logged += self.pending.len();
+            for record in self.pending.drain(..) {
+                record.serialize(&mut data)?;
+            }
+            Ok(Some(HistoryWrite::Append { network, data }))
+        }
+    }
+
+    /// Take a write if `interval` has elapsed since the last one.
+    pub fn take_write_if_elapsed(&mut self, interval: Duration) -> Result<Option<HistoryWrite>> {
+        if self.last_store.elapsed() >= interval {
+            self.take_write()
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Write the history to disk, including the bucket currently
+    /// being accumulated in each tier.
+    pub fn store(&mut self) -> Result<()> {
+        self.flush_buckets();
+        if let Some(write) = self.take_write()? {
+            write.commit()?;
+        }
+        Ok(())
+    }
+
+    /// Record the buckets currently being accumulated so that they are
+    /// not lost on exit. The buckets keep accumulating; once completed,
+    /// their final value replaces the flushed one.
+    fn flush_buckets(&mut self) {
+        for tier in Tier::ALL {
+            for (metric, bucket) in self.buckets[tier.index()].iter() {
+                if bucket.count > 0 {
+                    let point = (bucket.start as u32, bucket.average());
+                    Self::push(&mut self.tiers, &mut self.pending, tier, *metric, point);
+                }
+            }
+        }
+    }
+
+    fn push(tiers: &mut [HashMap<Metric, Series>; 3], pending: &mut Vec<LogRecord>, tier: Tier, metric: Metric, point: (u32, f32)) {
+        let series = tiers[tier.index()].entry(metric).or_default();
+        match series.back_mut() {
+            Some(last) if last.0 == point.0 => last.1 = point.1,
+            Some(last) if last.0 > point.0 => return,
+            _ => series.push_back(point),
+        }
+        pending.push(LogRecord {
+            tier: tier.index() as u8,
+            metric: metric.as_str().to_string(),
+            timestamp: point.0,
+            value: point.1,
+        });
+    }
+
+    /// Append a set of metric values sampled at `timestamp` (unix seconds).
+    pub fn ingest(&mut self, timestamp: u64, values: &HashMap<Metric, f64>) {
+        for (metric, value) in values {
+            let value = if value.is_finite() { *value } else { 0.0 };
+            for tier in Tier::ALL {
+                let start = timestamp - timestamp % tier.interval();
+                let buckets = &mut self.buckets[tier.index()];
+                let bucket = buckets.entry(*metric).or_insert(Bucket { start, ..Default::default() });
+                if bucket.start != start {
+                    if bucket.count > 0 {
+                        let point = (bucket.start as u32, bucket.average());
+                        Self::push(&mut self.tiers, &mut self.pending, tier, *metric, point);
+                    }
+                    *bucket = Bucket { start, ..Default::default() };
+                }
+                bucket.sum += value;
+                bucket.count += 1;
+            }
+        }
+
+        self.prune(timestamp);
+    }
+
+    fn prune(&mut self, now: u64) {
+        for tier in Tier::ALL {
+            let cutoff = now.saturating_sub(tier.retention()) as u32;
+            for series in self.tiers[tier.index()].values_mut() {
+                while series.front().is_some_and(|(timestamp, _)| *timestamp < cutoff) {
+                    series.pop_front();
+                }
+            }
+        }
+    }
+
+    /// Samples of `metric` between `from` and `to` (unix seconds) taken from
+    /// the finest tier covering `now - from`. The points are returned with
+    /// the `x` axis in milliseconds, matching live metrics data.
+    pub fn range(&self, metric: Metric, now: u64, from: u64, to: u64) -> Vec<PlotPoint> {
+        let tier = Tier::for_range(now.saturating_sub(from));
+
+        let mut points = self.tiers[tier.index()]
+            .get(&metric)
+            .map(|series| {
+                series
+                    .iter()
+                    .filter(|(timestamp, _)| (from..=to).contains(&(*timestamp as u64)))
+                    .map(|(timestamp, value)| PlotPoint::new(*timestamp as f64 * 1000.0, *value as f64))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        // include the bucket currently being accumulated
+        if let Some(bucket) = self.buckets[tier.index()].get(&metric) {
+            if bucket.count > 0 && (from..=to).contains(&bucket.start) {
+                let x = bucket.start as f64 * 1000.0;
+                // replace the value recorded for this bucket by a flush
+                if points.last().is_some_and(|point| point.x == x) {
+                    points.pop();
+                }
+                points.push(PlotPoint::new(x, bucket.average() as f64));
+            }
+        }
+
+        points
+    }
+
+    /// Per-second samples of the retained history, used to
+    /// seed live metrics data after a restart.
+    pub fn seconds(&self, metric: Metric) -> impl Iterator<Item = PlotPoint> + '_ {
+        self.tiers[Tier::Seconds.index()]
+            .get(&metric)
+            .into_iter()
+            .flatten()
+            .map(|(timestamp, value)| PlotPoint::new(*timestamp as f64 * 1000.0, *value as f64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metrics_history_downsampling() {
+        let mut history = MetricsHistory::new(Network::Devnet);
+        let start = 1_700_000_000 - 1_700_000_000 % 3600;
+
+        for second in 0..(2 * 3600 + 1) {
+            let values = HashMap::from([(Metric::NodeCpuUsage, (second % 60) as f64)]);
+            history.ingest(start + second, &values);
+        }
+
+        let now = start + 2 * 3600;
+
+        // seconds tier retains only the last hour
+        let seconds = history.range(Metric::NodeCpuUsage, now, now - 60, now);
+        assert_eq!(seconds.len(), 61);
+
+        // minute buckets average 0..59
+        let minutes = history.range(Metric::NodeCpuUsage, now, start, now);
+        assert_eq!(minutes.len(), 121);
+        assert!(minutes[..120].iter().all(|point| point.y == 29.5));
+
+        let hours = history.range(Metric::NodeCpuUsage, now, now - Tier::Minutes.retention() - 1, now);
+        assert_eq!(hours.len(), 3);
+        assert_eq!(hours[0].x, start as f64 * 1000.0);
+    }
+
+    #[test]
+    fn test_metrics_history_log_compaction() {
+        let mut history = MetricsHistory::new(Network::Devnet);
+        let start = 1_700_000_000 - 1_700_000_000 % 3600;
+        let values = HashMap::from([(Metric::NodeCpuUsage, 1.0)]);
+
+        for second in 0..11 {
+            history.ingest(start + second, &values);
+        }
+        // completed samples are appended to the log
+        assert!(matches!(history.take_write().unwrap(), Some(HistoryWrite::Append { .. })));
+        assert!(history.take_write().unwrap().is_none());
+
+        history.ingest(start + 11, &values);
+        assert!(matches!(history.take_write().unwrap(), Some(HistoryWrite::Append { .. })));
+
+        // an unreadable or outdated log is rewritten as a whole
+        history.needs_compaction = true;
+        assert!(matches!(history.take_write().unwrap(), Some(HistoryWrite::Compact { .. })));
+
+        // flushing the current buckets does not duplicate points
+        history.flush_buckets();
+        history.flush_buckets();
+        let seconds = history.range(Metric::NodeCpuUsage, start + 11, start, start + 11);
+        assert_eq!(seconds.len(), 12);
+    }
+}
@@ -6,9 +6,26 @@ use tondi_metrics_core::{Metric, Metrics, MetricsSnapshot};
 use tondi_wallet_core::rpc::{NotificationMode, Rpc, RpcCtl, WrpcEncoding};
 use tokio::time::{Duration, interval};
 
+mod history;
+pub use history::{HistoryWrite, MetricsHistory, Tier, MAX_METRICS_HISTORY_RANGE};
+
+mod alerts;
+pub use alerts::MetricAlerts;
+
+mod export;
+pub use export::{export_metrics, MetricsExportFormat};
+
+#[cfg(not(target_arch = "wasm32"))]
+mod prometheus;
+#[cfg(not(target_arch = "wasm32"))]
+pub use prometheus::PrometheusExporter;
+
 #[allow(clippy::identity_op)]
 pub const MAX_METRICS_SAMPLES: usize = 60 * 60 * 24 * 1; // 1 day
 
+/// Interval at which the metrics history is written to disk
+pub const METRICS_HISTORY_STORE_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
 pub struct MetricsService {
     pub application_events: ApplicationEventsChannel,
     pub task_ctl: Channel<()>,
@@ -17,10 +34,18 @@ pub struct MetricsService {
     pub samples_since_connection: Arc<AtomicUsize>,
     pub rpc_api: Mutex<Option<Arc<dyn RpcApi>>>,
     pub metrics_update_task: Mutex<Option<tokio::task::JoinHandle<()>>>,
+    pub history: Mutex<Option<MetricsHistory>>,
+    /// Serializes writes of the metrics history log
+    pub history_writer: Arc<Mutex<()>>,
+    pub latest_snapshot: Mutex<Option<MetricsSnapshot>>,
+    pub alerts: Mutex<MetricAlerts>,
+    pub prometheus_settings: Mutex<PrometheusSettings>,
+    #[cfg(not(target_arch = "wasm32"))]
+    pub prometheus_exporter: Mutex<Option<PrometheusExporter>>,
 }
 
 impl MetricsService {
-    pub fn new(application_events: ApplicationEventsChannel, _settings: &Settings) -> Self {
+    pub fn new(application_events: ApplicationEventsChannel, settings: &Settings) -> Self {
         let metrics = Arc::new(Metrics::default());
         let metrics_data = Metric::into_iter()
             .map(|metric| (metric, Vec::new()))
@@ -34,6 +59,13 @@ impl MetricsService {
             samples_since_connection: Arc::new(AtomicUsize::new(0)),
             rpc_api: Mutex::new(None),
             metrics_update_task: Mutex::new(None),
+            history: Mutex::new(None),
+            history_writer: Arc::new(Mutex::new(())),
+            latest_snapshot: Mutex::new(None),
+            alerts: Mutex::new(MetricAlerts::new(&settings.user_interface.metrics.alerts)),
+            prometheus_settings: Mutex::new(settings.prometheus.clone()),
+            #[cfg(not(target_arch = "wasm32"))]
+            prometheus_exporter: Mutex::new(None),
         }
     }
 
@@ -57,12 +89,72 @@ impl MetricsService {
         Ok(())
     }
 
-    pub fn ingest_metrics_snapshot(&self, snapshot: Box<MetricsSnapshot>) -> Result<()> {
-        let timestamp = snapshot.unixtime_millis;
+    /// Load the stored metrics history of `network` (storing the history
+    /// of the previously active network) and seed live metrics data with it.
+    pub fn load_history(&self, network: Network) -> Result<()> {
+        let mut history = self.history.lock().unwrap();
+        if let Some(mut previous) = history.take() {
+            let _writer = self.history_writer.lock().unwrap();
+            if let Err(err) = previous.store() {
+                log_error!("Unable to store metrics history for {}: {err}", previous.network());
+            }
+        }
+
+        let loaded = MetricsHistory::load(network);
         let mut metrics_data = self.metrics_data.lock().unwrap();
-        
-        println!("[METRICS] 开始处理MetricsSnapshot，时间戳: {}", timestamp);
-        
+        for metric in Metric::into_iter() {
+            let mut data = Vec::with_capacity(MAX_METRICS_SAMPLES);
+            data.extend(loaded.seconds(metric));
+            metrics_data.insert(metric, data);
+        }
+        history.replace(loaded);
+
+        Ok(())
+    }
+
+    pub fn store_history(&self) -> Result<()> {
+        if let Some(history) = self.history.lock().unwrap().as_mut() {
+            let _writer = self.history_writer.lock().unwrap();
+            history.store()?;
+        }
+        Ok(())
+    }
+
+    /// Commit a metrics history write outside of the metrics ingestion path.
+    fn commit_history_write(&self, write: HistoryWrite) {
+        let writer = self.history_writer.clone();
+        let commit = move || {
+            let _writer = writer.lock().unwrap();
+            if let Err(err) = write.commit() {
+                log_warn!("Unable to store metrics history: {err}");
+            }
+        };
+
+        cfg_if! {
+            if #[cfg(not(target_arch = "wasm32"))] {
+                tokio::task::spawn_blocking(commit);
+            } else {
+                commit();
+            }
+        }
+    }
+
+    /// Samples of `metric` within `range` (seconds relative to now, i.e. `-3600..0`),
+    /// taken from the metrics history tier matching the length of the range.
+    pub fn metrics_range(&self, metric: Metric, range: std::ops::Range<isize>) -> Vec<PlotPoint> {
+        let now = unixtime_as_millis_f64() as u64 / 1000;
+        let from = now.saturating_sub(range.start.unsigned_abs() as u64);
+        let to = now.saturating_sub(range.end.unsigned_abs() as u64);
+        self.history
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|history| history.range(metric, now, from, to))
+            .unwrap_or_default()
+    }
+
+    /// Map snapshot fields to their corresponding [`Metric`] values
+    pub fn metric_values(snapshot: &MetricsSnapshot) -> HashMap<Metric, f64> {
         // 直接使用我们自己的字段映射，而不是依赖MetricsSnapshot::get方法
         let mut metric_values = HashMap::new();
         
@@ -95,7 +187,18 @@ impl MetricsService {
         metric_values.insert(Metric::NetworkPastMedianTime, snapshot.network_past_median_time);
         metric_values.insert(Metric::NetworkVirtualParentHashesCount, snapshot.network_virtual_parent_hashes_count);
         metric_values.insert(Metric::NetworkVirtualDaaScore, snapshot.network_virtual_daa_score);
+
+        metric_values
+    }
+
+    pub fn ingest_metrics_snapshot(&self, snapshot: Box<MetricsSnapshot>) -> Result<()> {
+        let timestamp = snapshot.unixtime_millis;
+        let mut metrics_data = self.metrics_data.lock().unwrap();
+        
+        println!("[METRICS] 开始处理MetricsSnapshot，时间戳: {}", timestamp);
         
+        let metric_values = Self::metric_values(&snapshot);
+
         for metric in Metric::into_iter() {
             let dest = metrics_data.get_mut(&metric).unwrap();
             let y = metric_values.get(&metric).copied().unwrap_or(0.0);
@@ -107,10 +210,10 @@ impl MetricsService {
                 println!("[METRICS] 填充历史数据 - {}: {}", metric.as_str(), y);
                 // 使用当前时间戳作为基准，向前填充历史数据
                 // 每个数据点间隔1秒
-                let mut fill_timestamp = timestamp - (MAX_METRICS_SAMPLES - 1) as f64;
+                let mut fill_timestamp = timestamp - (MAX_METRICS_SAMPLES - 1) as f64 * 1000.0;
                 for _ in 0..(MAX_METRICS_SAMPLES - 1) {
                     dest.push(PlotPoint { x: fill_timestamp, y });
-                    fill_timestamp += 1.0; // 1秒间隔
+                    fill_timestamp += 1000.0; // 1秒间隔
                 }
             }
             if dest.len() > MAX_METRICS_SAMPLES {
@@ -133,6 +236,33 @@ impl MetricsService {
             }
         }
 
+        drop(metrics_data);
+
+        self.latest_snapshot.lock().unwrap().replace((*snapshot).clone());
+
+        let alerts = self.alerts.lock().unwrap().evaluate((timestamp / 1000.0) as u64, &metric_values);
+        for alert in alerts {
+            self.application_events
+                .sender
+                .try_send(crate::events::Events::Notify {
+                    user_notification: UserNotification::warning(alert),
+                })
+                .ok();
+        }
+
+        let history_write = self.history.lock().unwrap().as_mut().and_then(|history| {
+            history.ingest((timestamp / 1000.0) as u64, &metric_values);
+            history
+                .take_write_if_elapsed(METRICS_HISTORY_STORE_INTERVAL)
+                .unwrap_or_else(|err| {
+                    log_warn!("Unable to serialize metrics history: {err}");
+                    None
+                })
+        });
+        if let Some(write) = history_write {
+            self.commit_history_write(write);
+        }
+
         // 总是发送 Metrics 事件，不依赖于任何条件
         if let Err(e) = self.application_events
             .sender
@@ -155,6 +285,37 @@ impl MetricsService {
         Ok(())
     }
 
+    /// Latest metrics snapshot in the Prometheus text exposition format
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn to_prometheus(&self) -> Option<String> {
+        let snapshot = self.latest_snapshot.lock().unwrap().clone()?;
+        let network = runtime().tondi_service().network();
+        Some(prometheus::render(&Self::metric_values(&snapshot), network, snapshot.unixtime_millis))
+    }
+
+    /// Replace the alert rules evaluated on each metrics snapshot
+    pub fn update_alert_rules(&self, rules: &[MetricAlertRule]) {
+        self.alerts.lock().unwrap().update_rules(rules);
+    }
+
+    /// Start, restart or stop the Prometheus scrape endpoint
+    /// according to the supplied settings.
+    pub fn update_prometheus_exporter(&self, settings: &PrometheusSettings) -> Result<()> {
+        *self.prometheus_settings.lock().unwrap() = settings.clone();
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let mut exporter = self.prometheus_exporter.lock().unwrap();
+            // dropping the exporter stops its listener
+            exporter.take();
+            if settings.enable {
+                exporter.replace(PrometheusExporter::try_new(settings)?);
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn samples_since_connection(&self) -> usize {
         self.samples_since_connection.load(Ordering::SeqCst)
     }
@@ -368,7 +529,7 @@ impl Service for MetricsService {
                 None
             })));
 
-        self.reset_metrics_data()?;
+        self.load_history(runtime().tondi_service().network())?;
         
         // 禁用 tondi_metrics_core::Metrics task，只使用我们的手动实现
         println!("[METRICS] 禁用 tondi_metrics_core::Metrics，使用手动实现");
@@ -407,6 +568,8 @@ impl Service for MetricsService {
         
         self.metrics.bind_rpc(None);
 
+        self.store_history()?;
+
         Ok(())
     }
 
@@ -436,6 +599,9 @@ impl Service for MetricsService {
     }
 
     async fn disconnect_rpc(self: Arc<Self>) -> Result<()> {
+        // stop serving the snapshot of a node that is no longer connected
+        self.latest_snapshot.lock().unwrap().take();
+
         self.application_events
             .sender
             .try_send(crate::events::Events::NodeInfo { node_info: None })
@@ -444,10 +610,21 @@ impl Service for MetricsService {
     }
 
     async fn spawn(self: Arc<Self>) -> Result<()> {
+        let settings = self.prometheus_settings.lock().unwrap().clone();
+        if let Err(err) = self.update_prometheus_exporter(&settings) {
+            log_error!("Unable to start Prometheus exporter: {err}");
+        }
         Ok(())
     }
 
-    fn terminate(self: Arc<Self>) {}
+    fn terminate(self: Arc<Self>) {
+        #[cfg(not(target_arch = "wasm32"))]
+        self.prometheus_exporter.lock().unwrap().take();
+
+        if let Err(err) = self.store_history() {
+            log_error!("Unable to store metrics history: {err}");
+        }
+    }
 
     async fn join(self: Arc<Self>) -> Result<()> {
         Ok(())
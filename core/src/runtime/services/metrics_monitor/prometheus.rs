@@ -0,0 +1,138 @@
+use crate::imports::*;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::thread::JoinHandle;
+use tondi_metrics_core::Metric;
+
+/// Render metric values in the Prometheus text exposition format.
+pub fn render(values: &HashMap<Metric, f64>, network: Network, unixtime_millis: f64) -> String {
+    let mut text = String::new();
+    for metric in Metric::into_iter() {
+        let Some(value) = values.get(&metric).filter(|value| value.is_finite()) else {
+            continue;
+        };
+
+        let name = format!("tondi_{}", metric.as_str())
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '_' })
+            .collect::<String>();
+
+        text.push_str(&format!("# HELP {name} {}\n", metric.title().0));
+        text.push_str(&format!("# TYPE {name} gauge\n"));
+        text.push_str(&format!("{name}{{network=\"{network}\"}} {value} {}\n", unixtime_millis as u64));
+    }
+    text
+}
+
+/// Interval at which the idle listener checks for shutdown
+const ACCEPT_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Minimal HTTP server answering `GET /metrics` with the latest
+/// metrics snapshot, running on a dedicated thread.
+pub struct PrometheusExporter {
+    address: SocketAddr,
+    listener: Arc<Mutex<Option<TcpListener>>>,
+    shutdown: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl PrometheusExporter {
+    pub fn try_new(settings: &PrometheusSettings) -> Result<Self> {
+        let address = settings
+            .listen
+            .parse::<SocketAddr>()
+            .map_err(|err| Error::custom(format!("Invalid Prometheus listen address: {err}")))?;
+        let listener = TcpListener::bind(address)?;
+        // the listener is polled so that the thread can observe the shutdown
+        // flag without having to be woken up by a connection
+        listener.set_nonblocking(true)?;
+        let address = listener.local_addr()?;
+        // shared with the exporter so that dropping it closes the socket
+        // right away, even while the thread is serving a request
+        let listener = Arc::new(Mutex::new(Some(listener)));
+        let shutdown = Arc::new(AtomicBool::new(false));
+
+        let thread = std::thread::Builder::new()
+            .name("prometheus".to_string())
+            .spawn({
+                let listener = listener.clone();
+                let shutdown = shutdown.clone();
+                move || {
+                    while !shutdown.load(Ordering::SeqCst) {
+                        let accepted = listener.lock().unwrap().as_ref().map(TcpListener::accept);
+                        let Some(accepted) = accepted else {
+                            break;
+                        };
+                        match accepted {
+                            Ok((stream, _)) => {
+                                if let Err(err) = Self::handle(stream) {
+                                    log_warn!("Prometheus exporter: {err}");
+                                }
+                            }
+                            Err(err) if err.kind() == std::io::ErrorKind::WouldBlock => {
+                                std::thread::sleep(ACCEPT_POLL_INTERVAL);
+                            }
+                            Err(err) => {
+                                log_warn!("Prometheus exporter: {err}");
+                                std::thread::sleep(ACCEPT_POLL_INTERVAL);
+                            }
+                        }
+                    }
+                }
+            })?;
+
+        log_info!("Prometheus exporter listening on http://{address}/metrics");
+
+        Ok(Self {
+            address,
+            listener,
+            shutdown,
+            thread: Some(thread),
+        })
+    }
+
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    fn handle(mut stream: TcpStream) -> Result<()> {
+        stream.set_nonblocking(false)?;
+        stream.set_read_timeout(Some(Duration::from_secs(5)))?;
+
+        let mut request = String::new();
+        BufReader::new(&stream).read_line(&mut request)?;
+        let mut parts = request.split_whitespace();
+        let (method, path) = (parts.next().unwrap_or_default(), parts.next().unwrap_or_default());
+
+        let (status, body) = if method != "GET" || path.split('?').next() != Some("/metrics") {
+            ("404 Not Found", String::new())
+        } else if let Some(body) = runtime().metrics_service().to_prometheus() {
+            ("200 OK", body)
+        } else {
+            ("503 Service Unavailable", String::new())
+        };
+
+        write!(
+            stream,
+            "HTTP/1.1 {status}\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        )?;
+        stream.flush()?;
+        Ok(())
+    }
+}
+
+impl Drop for PrometheusExporter {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+        self.listener.lock().unwrap().take();
+        // a request in progress may take up to its read timeout to complete,
+        // so the thread is joined in the background to keep the caller (the
+        // settings UI) responsive
+        if let Some(thread) = self.thread.take() {
+            std::thread::spawn(move || {
+                let _ = thread.join();
+            });
+        }
+    }
+}
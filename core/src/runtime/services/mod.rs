@@ -25,6 +25,9 @@ cfg_if! {
 
         pub mod blockdag_monitor;
         pub use blockdag_monitor::BlockDagMonitorService;
+
+        pub mod mempool_monitor;
+        pub use mempool_monitor::MempoolMonitorService;
     }
 }
 
@@ -1,11 +1,19 @@
 use crate::imports::*;
-use tondi_rpc_core::RpcPeerInfo;
+use std::net::IpAddr;
+use tondi_rpc_core::{GetPeerAddressesResponse, RpcPeerInfo};
 
 pub const PEER_POLLING_INTERVAL_SECONDS: u64 = 1; // 1 sec
 
 pub enum PeerMonitorEvents {
     Enable,
     Disable,
+    FetchAddresses,
+    AddPeer {
+        address: ContextualNetAddress,
+        is_permanent: bool,
+    },
+    Ban(IpAddr),
+    Unban(IpAddr),
     Exit,
 }
 
@@ -15,6 +23,7 @@ pub struct PeerMonitorService {
     pub task_ctl: Channel<()>,
     pub rpc_api: Mutex<Option<Arc<dyn RpcApi>>>,
     pub peer_info: Mutex<Option<Arc<Vec<RpcPeerInfo>>>>,
+    pub peer_addresses: Mutex<Option<Arc<GetPeerAddressesResponse>>>,
     pub is_enabled: Arc<AtomicBool>,
 }
 
@@ -26,6 +35,7 @@ impl PeerMonitorService {
             task_ctl: Channel::oneshot(),
             rpc_api: Mutex::new(None),
             peer_info: Mutex::new(None),
+            peer_addresses: Mutex::new(None),
             is_enabled: Arc::new(AtomicBool::new(false)),
         }
     }
@@ -38,6 +48,10 @@ impl PeerMonitorService {
         self.peer_info.lock().unwrap().clone()
     }
 
+    pub fn peer_addresses(&self) -> Option<Arc<GetPeerAddressesResponse>> {
+        self.peer_addresses.lock().unwrap().clone()
+    }
+
     pub fn enable(&self) {
         self.service_events
             .sender
@@ -51,6 +65,67 @@ impl PeerMonitorService {
             .try_send(PeerMonitorEvents::Disable)
             .unwrap();
     }
+
+    /// Request a refresh of the known and banned peer addresses.
+    pub fn fetch_peer_addresses(&self) {
+        self.service_events
+            .sender
+            .try_send(PeerMonitorEvents::FetchAddresses)
+            .unwrap();
+    }
+
+    pub fn add_peer(&self, address: ContextualNetAddress, is_permanent: bool) {
+        self.service_events
+            .sender
+            .try_send(PeerMonitorEvents::AddPeer {
+                address,
+                is_permanent,
+            })
+            .unwrap();
+    }
+
+    pub fn ban(&self, ip: IpAddr) {
+        self.service_events
+            .sender
+            .try_send(PeerMonitorEvents::Ban(ip))
+            .unwrap();
+    }
+
+    pub fn unban(&self, ip: IpAddr) {
+        self.service_events
+            .sender
+            .try_send(PeerMonitorEvents::Unban(ip))
+            .unwrap();
+    }
+
+    async fn update_peer_addresses(&self) {
+        if let Some(rpc_api) = self.rpc_api() {
+            match rpc_api.get_peer_addresses().await {
+                Ok(resp) => {
+                    self.peer_addresses.lock().unwrap().replace(Arc::new(resp));
+                    runtime().request_repaint();
+                }
+                Err(e) => {
+                    log_warn!("[PeerMonitor] Failed to get peer addresses: {}", e);
+                }
+            }
+        }
+    }
+
+    /// Report the outcome of a peer management action to the
+    /// user and refresh the peer address lists.
+    async fn report_action(&self, result: std::result::Result<(), tondi_rpc_core::RpcError>, success: String) {
+        match result {
+            Ok(()) => {
+                runtime().notify(UserNotification::success(success));
+            }
+            Err(e) => {
+                runtime().error(e.to_string());
+            }
+        }
+
+        self.update_peer_addresses().await;
+    }
 }
 
 #[async_trait]
@@ -67,6 +142,7 @@ impl Service for PeerMonitorService {
     async fn detach_rpc(self: Arc<Self>) -> Result<()> {
         self.rpc_api.lock().unwrap().take();
         self.peer_info.lock().unwrap().take();
+        self.peer_addresses.lock().unwrap().take();
 
         Ok(())
     }
@@ -117,6 +193,31 @@ impl Service for PeerMonitorService {
                             PeerMonitorEvents::Disable => {
                                 self.is_enabled.store(false, Ordering::Relaxed);
                                 this.peer_info.lock().unwrap().take();
+                                this.peer_addresses.lock().unwrap().take();
+                            }
+                            PeerMonitorEvents::FetchAddresses => {
+                                this.update_peer_addresses().await;
+                            }
+                            PeerMonitorEvents::AddPeer { address, is_permanent } => {
+                                if let Some(rpc_api) = this.rpc_api() {
+                                    let success = i18n_args("Peer {address} added", &[("address", &address.to_string())]);
+                                    let result = rpc_api.add_peer(address, is_permanent).await;
+                                    this.report_action(result, success).await;
+                                }
+                            }
+                            PeerMonitorEvents::Ban(ip) => {
+                                if let Some(rpc_api) = this.rpc_api() {
+                                    let success = i18n_args("{ip} has been banned", &[("ip", &ip.to_string())]);
+                                    let result = rpc_api.ban(ip.into()).await;
+                                    this.report_action(result, success).await;
+                                }
+                            }
+                            PeerMonitorEvents::Unban(ip) => {
+                                if let Some(rpc_api) = this.rpc_api() {
+                                    let success = i18n_args("{ip} has been unbanned", &[("ip", &ip.to_string())]);
+                                    let result = rpc_api.unban(ip.into()).await;
+                                    this.report_action(result, success).await;
+                                }
                             }
                             PeerMonitorEvents::Exit => {
                                 break;
@@ -1,6 +1,6 @@
 use crate::app::{GIT_DESCRIBE, VERSION};
 use crate::imports::*;
-use crate::settings::NodeMemoryScale;
+use crate::settings::{NodeMemoryScale, TondidTerminationMethod};
 use crate::utils::Arglist;
 use tondi_core::tondid_env;
 #[cfg(not(target_arch = "wasm32"))]
@@ -36,6 +36,8 @@ pub struct Config {
     tondid_daemon_storage_folder: String,
     memory_scale: NodeMemoryScale,
     devnet_custom_url: Option<String>,
+    termination: TondidTerminationMethod,
+    termination_timeout: Duration,
 }
 
 impl From<NodeSettings> for Config {
@@ -57,6 +59,8 @@ impl From<NodeSettings> for Config {
             tondid_daemon_storage_folder: node_settings.tondid_daemon_storage_folder,
             memory_scale: node_settings.memory_scale,
             devnet_custom_url: node_settings.devnet_custom_url,
+            termination: node_settings.tondid_daemon_termination,
+            termination_timeout: Duration::from_secs(node_settings.tondid_daemon_termination_timeout),
         };
         
         println!("[CONFIG DEBUG] 生成的Config.grpc_network_interface: {:?}", config.grpc_network_interface);
@@ -67,10 +71,7 @@ impl From<NodeSettings> for Config {
 impl Config {
     /// 从网络类型创建默认配置
     pub fn from_network(network: Network) -> Self {
-        let default_grpc_interface = NetworkInterfaceConfig {
-            kind: NetworkInterfaceKind::Custom,
-            custom: "127.0.0.1:16110".parse().unwrap(),
-        };
+        let default_grpc_interface = network_defaults().get(network).grpc_interface();
 
         Self {
             network,
@@ -85,8 +86,15 @@ impl Config {
             tondid_daemon_storage_folder: String::default(),
             memory_scale: NodeMemoryScale::default(),
             devnet_custom_url: None,
+            termination: TondidTerminationMethod::default(),
+            termination_timeout: Duration::from_secs(30),
         }
     }
+
+    /// Method and timeout used to stop the daemon started with this configuration
+    pub fn termination(&self) -> (TondidTerminationMethod, Duration) {
+        (self.termination, self.termination_timeout)
+    }
 }
 
 cfg_if! {
@@ -122,7 +130,7 @@ cfg_if! {
                 args.user_agent_comments = vec![user_agent_comment()];
 
                 // Add custom devnet URL if specified
-                if let Some(custom_url) = &config.devnet_custom_url {
+                if let Some(custom_url) = config.devnet_custom_url.as_ref().filter(|_| config.network == Network::Devnet) {
                     if !custom_url.is_empty() {
                         // Add the custom URL as a command line argument
                         // This will be parsed by tondid to connect to the custom devnet node
@@ -183,7 +191,7 @@ cfg_if! {
                 args.push(format!("--uacomment={}", user_agent_comment()));
 
                 // Add custom devnet URL if specified
-                if let Some(custom_url) = &config.devnet_custom_url {
+                if let Some(custom_url) = config.devnet_custom_url.as_ref().filter(|_| config.network == Network::Devnet) {
                     if !custom_url.is_empty() {
                         // Add the custom URL as a command line argument
                         args.push(format!("--uacomment=devnet-url:{}", custom_url));
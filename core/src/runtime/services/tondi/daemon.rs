@@ -1,27 +1,17 @@
 use crate::imports::*;
-use crate::runtime::services::tondi::{Config, TondidServiceEvents};
+use crate::runtime::services::tondi::{Config, DaemonShutdown, TondidServiceEvents};
+use crate::settings::TondidTerminationMethod;
 use std::process::Stdio;
 use tokio::io::{AsyncBufReadExt, BufReader};
 use tokio::process::Command;
 use workflow_core::prelude::DuplexChannel;
 
-/// Termination method with which to terminate the tondid process.
-/// This should remain Sigkill until Tondid learns to terminate
-/// rapidly during it's sync process.
-#[derive(Default, Debug, Clone, Eq, PartialEq)]
-enum TerminationMethod {
-    #[default]
-    Sigkill,
-    Sigterm,
-}
-
 struct Inner {
     path: Option<PathBuf>,
     is_running: Arc<AtomicBool>,
     pid: Mutex<Option<u32>>,
     service_events: Channel<TondidServiceEvents>,
     task_ctl: DuplexChannel,
-    termination_method: TerminationMethod,
 }
 
 #[derive(Clone)]
@@ -38,7 +28,6 @@ impl Daemon {
                 pid: Mutex::new(None),
                 service_events: (*service_events).clone(),
                 task_ctl: DuplexChannel::oneshot(),
-                termination_method: TerminationMethod::default(),
             }),
         }
     }
@@ -52,13 +41,29 @@ impl Daemon {
     }
 
     #[cfg(unix)]
-    fn sigterm(&self, pid: u32) {
+    fn sigterm(&self, pid: u32) -> bool {
         use nix::sys::signal::Signal;
         use nix::unistd::Pid;
         if let Err(err) = nix::sys::signal::kill(Pid::from_raw(pid as i32), Signal::SIGTERM) {
             println!("tondid sigterm error: {:?}", err);
+            false
+        } else {
+            true
         }
     }
+
+    #[cfg(not(unix))]
+    fn sigterm(&self, _pid: u32) -> bool {
+        false
+    }
+
+    fn notify_shutdown(progress: Option<DaemonShutdown>) {
+        runtime()
+            .application_events()
+            .sender
+            .try_send(Events::DaemonShutdown { progress })
+            .ok();
+    }
 }
 
 #[async_trait]
@@ -71,6 +76,8 @@ impl super::Tondid for Daemon {
             Command::new(path)
         };
 
+        let (termination, termination_timeout) = config.termination();
+
         let cmd = cmd
             .args(config)
             .env("TONDI_NG_DAEMON", "1")
@@ -89,42 +96,53 @@ impl super::Tondid for Daemon {
 
         let this = self.clone();
 
-        cfg_if::cfg_if! {
-            if #[cfg(unix)] {
-                let is_unix = true;
-            } else {
-                let is_unix = false;
-            }
-        }
-
         tokio::spawn(async move {
-            loop {
+            let mut stdout_open = true;
+            let mut stop_requested = false;
+            // start of a graceful shutdown awaiting the daemon exit
+            let mut sigterm_sent: Option<Instant> = None;
+            let mut ticker = tokio::time::interval(Duration::from_secs(1));
+
+            let status = loop {
+                let next_line = async {
+                    if stdout_open {
+                        reader.next_line().await
+                    } else {
+                        futures::future::pending().await
+                    }
+                };
+
                 select! {
                     _ = task_ctl.request.recv().fuse() => {
-                        if this.inner.termination_method == TerminationMethod::Sigterm && is_unix {
-                            let pid = this.inner.pid.lock().unwrap();
-                            if let Some(_pid) = *pid {
-                                #[cfg(unix)]
-                                this.sigterm(_pid);
-                            }
+                        stop_requested = true;
+                        let pid = *this.inner.pid.lock().unwrap();
+                        let graceful = termination == TondidTerminationMethod::Sigterm
+                            && pid.is_some_and(|pid| this.sigterm(pid));
+                        if graceful {
+                            sigterm_sent = Some(Instant::now());
+                            Daemon::notify_shutdown(Some(DaemonShutdown { elapsed: Duration::ZERO, timeout: termination_timeout }));
                         } else if let Err(err) = child.start_kill() {
                             println!("tondi daemon start_kill error: {:?}", err);
                         }
                     }
-                    status = child.wait().fuse() => {
-                        match status {
-                            Ok(_status) => {
-                                // println!("tondid shutdown: {:?}", _status);
-                            }
-                            Err(err) => {
-                                println!("tondid shutdown error: {:?}", err);
+                    _ = ticker.tick().fuse() => {
+                        if let Some(instant) = sigterm_sent {
+                            let elapsed = instant.elapsed();
+                            if elapsed >= termination_timeout {
+                                log_warn!("tondid did not exit within {} seconds, terminating with SIGKILL", termination_timeout.as_secs());
+                                sigterm_sent = None;
+                                if let Err(err) = child.start_kill() {
+                                    println!("tondi daemon start_kill error: {:?}", err);
+                                }
+                            } else {
+                                Daemon::notify_shutdown(Some(DaemonShutdown { elapsed, timeout: termination_timeout }));
                             }
                         }
-                        is_running.store(false,Ordering::SeqCst);
-                        break;
                     }
-
-                    line = reader.next_line().fuse() => {
+                    status = child.wait().fuse() => {
+                        break status;
+                    }
+                    line = next_line.fuse() => {
                         match line {
                             Ok(Some(line)) => {
                                 // 过滤掉log4rs的Broken pipe错误，这些是正常的管道中断
@@ -132,21 +150,42 @@ impl super::Tondid for Daemon {
                                     // println!("tondid: {}", line);
                                     if let Err(e) = stdout_relay_sender.send(TondidServiceEvents::Stdout { line }).await {
                                         println!("Failed to send stdout event: {}", e);
-                                        break; // 如果无法发送事件，退出循环
+                                        stdout_open = false;
                                     }
                                 }
                             }
                             Ok(None) => {
                                 // stdout 已关闭
-                                break;
+                                stdout_open = false;
                             }
                             Err(e) => {
                                 println!("Error reading tondid stdout: {}", e);
-                                break;
+                                stdout_open = false;
                             }
                         }
                     }
                 }
+            };
+
+            is_running.store(false, Ordering::SeqCst);
+
+            if sigterm_sent.is_some() || stop_requested {
+                Daemon::notify_shutdown(None);
+            }
+
+            match status {
+                Ok(status) if !stop_requested => {
+                    stdout_relay_sender
+                        .send(TondidServiceEvents::DaemonExit { code: status.code() })
+                        .await
+                        .ok();
+                }
+                Ok(_status) => {
+                    // println!("tondid shutdown: {:?}", _status);
+                }
+                Err(err) => {
+                    println!("tondid shutdown error: {:?}", err);
+                }
             }
 
             task_ctl.response.send(()).await.unwrap();
@@ -1,80 +1,428 @@
 use crate::imports::*;
 use crate::network::Network;
 use async_trait::async_trait;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 
 use tondi_rpc_core::{
     GetMetricsResponse,
     GetServerInfoResponse, GetConnectedPeerInfoResponse, GetBlockCountResponse, GetBlockDagInfoResponse,
-    RpcNetworkId, RpcResult,
+    RpcResult,
 };
+use tondi_rpc_core::api::ctl::RpcCtl;
 use tondi_rpc_core::api::rpc::RpcApi;
+use tondi_rpc_core::notify::connection::ChannelConnection;
+use tondi_rpc_core::notify::mode::NotificationMode;
 use tondi_consensus_core::api::BlockCount;
+use tondi_notify::{listener::ListenerId, scope::Scope};
 
 
 // 使用TONDI项目的真实gRPC客户端
 use tondi_grpc_client::GrpcClient;
 
+/// 重新连接的初始等待时间
+const RECONNECT_INITIAL_DELAY: Duration = Duration::from_secs(1);
+/// 重新连接的最大等待时间（指数退避的上限）
+const RECONNECT_MAX_DELAY: Duration = Duration::from_secs(30);
+/// 连接状态下的心跳检测间隔
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(5);
+/// 心跳请求的超时时间
+const HEARTBEAT_TIMEOUT: Duration = Duration::from_secs(10);
+/// 节点连续处于未同步状态的心跳次数达到该值时切换到其他端点
+const OUT_OF_SYNC_TOLERANCE: u32 = 3;
+
+/// 心跳检测结果
+enum Health {
+    Healthy,
+    OutOfSync,
+    Unreachable,
+}
+
+/// 通知监听器记录
+///
+/// 监听器ID由本客户端分配，与底层`GrpcClient`通知器中的ID相互独立，
+/// 这样在重新连接（底层客户端被替换）后，可以重新注册监听器并恢复其订阅范围。
+#[derive(Clone)]
+struct ListenerRecord {
+    connection: ChannelConnection,
+    inner_id: Option<ListenerId>,
+    scopes: Vec<Scope>,
+}
+
+impl ListenerRecord {
+    /// 记录新的订阅范围
+    ///
+    /// `UtxosChanged`订阅按地址分批进行，因此其地址集合会被合并；
+    /// 空地址列表表示订阅全部地址。其他事件类型只保留最新的订阅范围。
+    fn add_scope(&mut self, scope: &Scope) {
+        if let Scope::UtxosChanged(added) = scope {
+            if let Some(Scope::UtxosChanged(current)) = self.scopes.iter_mut().find(|s| matches!(s, Scope::UtxosChanged(_))) {
+                if added.addresses.is_empty() {
+                    current.addresses.clear();
+                } else if !current.addresses.is_empty() {
+                    let mut known = current.addresses.iter().cloned().collect::<AHashSet<_>>();
+                    for address in added.addresses.iter() {
+                        if known.insert(address.clone()) {
+                            current.addresses.push(address.clone());
+                        }
+                    }
+                }
+                return;
+            }
+        }
+
+        self.scopes.retain(|s| std::mem::discriminant(s) != std::mem::discriminant(scope));
+        self.scopes.push(scope.clone());
+    }
+
+    /// 移除订阅范围
+    ///
+    /// 对`UtxosChanged`只移除给定的地址，地址集合为空时才移除整个订阅；
+    /// 空地址列表表示停止全部地址的订阅。
+    fn remove_scope(&mut self, scope: &Scope) {
+        if let Scope::UtxosChanged(removed) = scope {
+            if !removed.addresses.is_empty() {
+                let mut drained = false;
+                if let Some(Scope::UtxosChanged(current)) = self.scopes.iter_mut().find(|s| matches!(s, Scope::UtxosChanged(_))) {
+                    // 订阅全部地址时无法逐个移除，保持订阅不变
+                    if current.addresses.is_empty() {
+                        return;
+                    }
+                    let removed = removed.addresses.iter().collect::<AHashSet<_>>();
+                    current.addresses.retain(|address| !removed.contains(address));
+                    drained = current.addresses.is_empty();
+                }
+                if drained {
+                    self.scopes.retain(|s| !matches!(s, Scope::UtxosChanged(_)));
+                }
+                return;
+            }
+        }
+
+        self.scopes.retain(|s| std::mem::discriminant(s) != std::mem::discriminant(scope));
+    }
+}
+
 /// Tondi gRPC客户端，使用TONDI项目的真实gRPC客户端
 #[derive(Clone)]
 pub struct TondiGrpcClient {
     grpc_client: Arc<Mutex<Option<GrpcClient>>>,
-    url: String,
+    /// 按优先级排序的端点列表，第一个为首选端点
+    endpoints: Arc<Vec<String>>,
+    active_endpoint: Arc<AtomicUsize>,
+    /// 是否已经成功连接过某个端点（用于区分首次连接与端点切换）
+    has_activated: Arc<AtomicBool>,
     network: Network,
     is_connected: Arc<AtomicBool>,
+    listeners: Arc<Mutex<HashMap<ListenerId, ListenerRecord>>>,
+    next_listener_id: Arc<AtomicU64>,
+    rpc_ctl: RpcCtl,
+    is_monitoring: Arc<AtomicBool>,
+    monitor_ctl: Channel<()>,
 }
 
 impl TondiGrpcClient {
     pub async fn connect(network_interface: NetworkInterfaceConfig, network: Network) -> Result<Self> {
+        Self::connect_with_failover(vec![network_interface], network).await
+    }
 
-        let url = format!("grpc://{}", network_interface);
-        
-        // 使用TONDI项目的真实gRPC客户端
-        match GrpcClient::connect(url.clone()).await {
-            Ok(grpc_client) => {
-                Ok(Self {
-                    grpc_client: Arc::new(Mutex::new(Some(grpc_client))),
-                    url: url.clone(),
-                    network,
-                    is_connected: Arc::new(AtomicBool::new(true)),
-                })
+    /// 使用按优先级排序的端点列表创建客户端
+    ///
+    /// 依次尝试各端点，连接到第一个可用的端点。当前端点断开或失去同步时，
+    /// 后台任务会自动切换到列表中的其他端点。
+    pub async fn connect_with_failover(endpoints: Vec<NetworkInterfaceConfig>, network: Network) -> Result<Self> {
+        if endpoints.is_empty() {
+            return Err(Error::custom("No gRPC endpoints configured"));
+        }
+
+        let endpoints = endpoints
+            .into_iter()
+            .map(|network_interface| format!("grpc://{}", network_interface))
+            .collect::<Vec<_>>();
+
+        let this = Self {
+            grpc_client: Arc::new(Mutex::new(None)),
+            endpoints: Arc::new(endpoints),
+            active_endpoint: Arc::new(AtomicUsize::new(0)),
+            has_activated: Arc::new(AtomicBool::new(false)),
+            network,
+            is_connected: Arc::new(AtomicBool::new(false)),
+            listeners: Arc::new(Mutex::new(HashMap::new())),
+            next_listener_id: Arc::new(AtomicU64::new(1)),
+            rpc_ctl: RpcCtl::new(),
+            is_monitoring: Arc::new(AtomicBool::new(false)),
+            monitor_ctl: Channel::unbounded(),
+        };
+
+        if let Err(e) = this.reconnect().await {
+            // 返回一个未连接的客户端，由后台任务负责重新连接
+            log_warn!("[TONDI GRPC] 无法连接到任何端点: {}，将在后台重试", e);
+        }
+
+        Ok(this)
+    }
+
+    /// 创建底层gRPC客户端并启动其通知器
+    ///
+    /// 客户端以`MultiListeners`模式运行，每个监听器拥有独立的订阅范围。
+    async fn connect_grpc_client(url: &str) -> Result<GrpcClient> {
+        let grpc_client = GrpcClient::connect_with_args(
+            NotificationMode::MultiListeners,
+            url.to_string(),
+            None,
+            false,
+            None,
+            false,
+            None,
+            Default::default(),
+        )
+        .await
+        .map_err(|e| Error::custom(format!("Failed to connect to {}: {}", url, e)))?;
+        grpc_client.start(None).await;
+        Ok(grpc_client)
+    }
+
+    /// 检查连接状态
+    ///
+    /// 断开后的重新连接由后台任务负责，这里只在任务尚未运行时启动它。
+    fn ensure_connected(&self) -> Result<()> {
+        if self.is_connected() {
+            return Ok(());
+        }
+
+        self.start_monitor();
+        Err(Error::custom(format!("Connection to {} is not available, reconnecting...", self.active_url())))
+    }
+
+    /// 从当前端点开始依次尝试各端点，连接到第一个可用的端点
+    ///
+    /// 从当前端点而不是首选端点开始，避免首选端点不稳定时反复切换。
+    async fn reconnect(&self) -> Result<()> {
+        let mut last_error = None;
+        let start = self.active_endpoint.load(Ordering::Acquire);
+        for offset in 0..self.endpoints.len() {
+            let index = (start + offset) % self.endpoints.len();
+            let url = &self.endpoints[index];
+            match Self::connect_grpc_client(url).await {
+                Ok(grpc_client) => {
+                    self.activate(index, grpc_client).await;
+                    return Ok(());
+                }
+                Err(e) => {
+                    log_warn!("[TONDI GRPC] 无法连接到 {}: {}", url, e);
+                    last_error = Some(e);
+                }
+            }
+        }
+
+        Err(last_error.unwrap_or_else(|| Error::custom("No gRPC endpoints configured")))
+    }
+
+    /// 将新的底层客户端设为当前客户端，恢复监听器并更新连接状态
+    async fn activate(&self, index: usize, grpc_client: GrpcClient) {
+        // 在新的客户端上恢复已注册的监听器及其订阅
+        self.restore_listeners(&grpc_client).await;
+        // 更新连接状态和客户端
+        {
+            let mut client_guard = self.grpc_client.lock().unwrap();
+            *client_guard = Some(grpc_client);
+        }
+
+        let url = self.endpoints[index].clone();
+        self.rpc_ctl.set_descriptor(Some(url.clone()));
+        let previous = self.active_endpoint.swap(index, Ordering::AcqRel);
+        let is_first_connection = !self.has_activated.swap(true, Ordering::AcqRel);
+        self.is_connected.store(true, Ordering::Release);
+
+        // 首次连接时跳过了不可用的端点不属于端点切换
+        if !is_first_connection && previous != index {
+            runtime().notify(UserNotification::warning(i18n_args(
+                "RPC endpoint switched from {previous} to {url}",
+                &[("previous", self.endpoints[previous].as_str()), ("url", url.as_str())],
+            )));
+        }
+    }
+
+    /// 当前端点失去同步时，切换到第一个已同步的其他端点
+    ///
+    /// 如果没有其他已同步的端点，则保持当前连接。
+    async fn failover_to_synced(&self) -> bool {
+        let active = self.active_endpoint.load(Ordering::Acquire);
+        for (index, url) in self.endpoints.iter().enumerate() {
+            if index == active {
+                continue;
             }
-            Err(_e) => {
-                // 返回一个未连接的客户端，后续可以重试
-                Ok(Self {
-                    grpc_client: Arc::new(Mutex::new(None)),
-                    url: url.clone(),
-                    network,
-                    is_connected: Arc::new(AtomicBool::new(false)),
-                })
+
+            let Ok(grpc_client) = Self::connect_grpc_client(url).await else {
+                continue;
+            };
+
+            if matches!(Self::check_health(&grpc_client).await, Health::Healthy) {
+                // 先通知钱包断开当前连接，再在新端点上重新打开，使钱包重新同步
+                self.handle_disconnect().await;
+                self.activate(index, grpc_client).await;
+                if let Err(e) = self.rpc_ctl.signal_open().await {
+                    log_error!("[TONDI GRPC] 发送连接信号失败: {}", e);
+                }
+                return true;
             }
+
+            grpc_client.disconnect().await.ok();
         }
+
+        false
     }
 
-    /// 检查连接状态，如果未连接则尝试重新连接
-    async fn ensure_connected(&self) -> Result<()> {
-        if self.is_connected.load(Ordering::Acquire) {
-            return Ok(());
+    /// 标记连接已断开，释放底层客户端并通知钱包
+    async fn handle_disconnect(&self) {
+        if !self.is_connected.swap(false, Ordering::AcqRel) {
+            return;
+        }
+
+        let grpc_client = self.grpc_client.lock().unwrap().take();
+        if let Some(grpc_client) = grpc_client {
+            grpc_client.disconnect().await.ok();
+        }
+
+        if let Err(e) = self.rpc_ctl.signal_close().await {
+            log_error!("[TONDI GRPC] 发送断开信号失败: {}", e);
         }
+    }
 
-        match GrpcClient::connect(self.url.clone()).await {
-            Ok(grpc_client) => {
-                // 更新连接状态和客户端
-                {
-                    let mut client_guard = self.grpc_client.lock().unwrap();
-                    *client_guard = Some(grpc_client);
+    /// 通过节点信息检测当前连接是否可用以及节点是否已同步
+    async fn heartbeat(&self) -> Health {
+        let Ok(grpc_client) = self.current_client() else {
+            return Health::Unreachable;
+        };
+
+        Self::check_health(&grpc_client).await
+    }
+
+    async fn check_health(grpc_client: &GrpcClient) -> Health {
+        select! {
+            result = grpc_client.get_server_info_call(None, tondi_rpc_core::GetServerInfoRequest {}).fuse() => match result {
+                Ok(info) if info.is_synced => Health::Healthy,
+                Ok(_) => Health::OutOfSync,
+                Err(_) => Health::Unreachable,
+            },
+            _ = task::sleep(HEARTBEAT_TIMEOUT).fuse() => Health::Unreachable,
+        }
+    }
+
+    /// 启动后台连接监控任务
+    ///
+    /// 任务在连接状态下定期发送心跳，在断开后以带抖动的指数退避方式重新连接，
+    /// 并通过[`RpcCtl`]发出连接/断开信号，使钱包及各服务的行为与wRPC一致。
+    pub fn start_monitor(&self) {
+        if self.is_monitoring.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        let this = self.clone();
+        task::spawn(async move {
+            this.monitor_task().await;
+        });
+    }
+
+    /// 停止后台连接监控任务并断开连接
+    pub async fn disconnect(&self) -> Result<()> {
+        if self.is_monitoring.swap(false, Ordering::SeqCst) {
+            self.monitor_ctl.sender.try_send(()).ok();
+        }
+        self.handle_disconnect().await;
+        Ok(())
+    }
+
+    async fn monitor_task(&self) {
+        let mut delay = RECONNECT_INITIAL_DELAY;
+        let mut out_of_sync = 0;
+
+        loop {
+            let wait = if self.is_connected() {
+                HEARTBEAT_INTERVAL
+            } else {
+                with_jitter(delay)
+            };
+
+            select! {
+                _ = self.monitor_ctl.receiver.recv().fuse() => {
+                    break;
                 }
-                self.is_connected.store(true, Ordering::Release);
-                println!("[TONDI GRPC] 重新连接成功");
-                Ok(())
+                _ = task::sleep(wait).fuse() => {}
             }
-            Err(e) => {
-                println!("[TONDI GRPC] 重新连接失败: {}", e);
-                Err(Error::custom(format!("Failed to reconnect: {}", e)))
+
+            if self.is_connected() {
+                match self.heartbeat().await {
+                    Health::Healthy => {
+                        out_of_sync = 0;
+                    }
+                    Health::OutOfSync => {
+                        out_of_sync += 1;
+                        if self.endpoints.len() > 1 && out_of_sync >= OUT_OF_SYNC_TOLERANCE {
+                            log_warn!("[TONDI GRPC] 节点 {} 未同步，尝试切换端点", self.active_url());
+                            if !self.failover_to_synced().await {
+                                log_warn!("[TONDI GRPC] 没有其他已同步的端点可用");
+                            }
+                            out_of_sync = 0;
+                        }
+                    }
+                    Health::Unreachable => {
+                        log_warn!("[TONDI GRPC] 与节点 {} 的连接已断开", self.active_url());
+                        self.handle_disconnect().await;
+                        delay = RECONNECT_INITIAL_DELAY;
+                        out_of_sync = 0;
+                    }
+                }
+            } else {
+                match self.reconnect().await {
+                    Ok(()) => {
+                        log_info!("[TONDI GRPC] 重新连接成功");
+                        delay = RECONNECT_INITIAL_DELAY;
+                        if let Err(e) = self.rpc_ctl.signal_open().await {
+                            log_error!("[TONDI GRPC] 发送连接信号失败: {}", e);
+                        }
+                    }
+                    Err(e) => {
+                        delay = (delay * 2).min(RECONNECT_MAX_DELAY);
+                        log_warn!("[TONDI GRPC] 重新连接失败: {}，{}秒后重试", e, delay.as_secs());
+                    }
+                }
+            }
+        }
+    }
+
+    /// 将所有监听器重新注册到新的底层客户端，并重新启动其订阅
+    async fn restore_listeners(&self, grpc_client: &GrpcClient) {
+        let records = self.listeners.lock().unwrap().clone();
+        for (id, record) in records {
+            let inner_id = grpc_client.register_new_listener(record.connection.clone());
+            if let Some(listener) = self.listeners.lock().unwrap().get_mut(&id) {
+                listener.inner_id = Some(inner_id);
+            }
+            for scope in record.scopes {
+                if let Err(e) = grpc_client.start_notify(inner_id, scope.clone()).await {
+                    log_error!("[TONDI GRPC] 恢复监听器 {} 的订阅 {:?} 失败: {}", id, scope, e);
+                }
             }
         }
     }
 
+    /// 获取当前的gRPC客户端（不尝试重新连接）
+    fn current_client(&self) -> RpcResult<GrpcClient> {
+        let client_guard = self.grpc_client.lock().unwrap();
+        client_guard
+            .clone()
+            .ok_or_else(|| tondi_rpc_core::RpcError::General("No gRPC client available".to_string()))
+    }
+
+    /// 获取可用的gRPC客户端，未连接时返回错误
+    async fn active_client(&self) -> RpcResult<GrpcClient> {
+        if let Err(e) = self.ensure_connected() {
+            return Err(tondi_rpc_core::RpcError::General(format!("Not connected: {}", e)));
+        }
+
+        self.current_client()
+    }
+
     /// 获取内部客户端引用
     pub fn client(&self) -> &Self {
         self
@@ -84,6 +432,11 @@ impl TondiGrpcClient {
     pub fn is_connected(&self) -> bool {
         self.is_connected.load(Ordering::Acquire)
     }
+
+    /// 获取连接控制对象，连接状态的变化通过它通知钱包
+    pub fn ctl(&self) -> &RpcCtl {
+        &self.rpc_ctl
+    }
     
     /// 获取网络ID（如果可用）
     pub fn network_id(&self) -> Option<tondi_consensus_core::network::NetworkId> {
@@ -92,7 +445,12 @@ impl TondiGrpcClient {
     
     /// 获取服务器URL
     pub fn url(&self) -> Option<String> {
-        Some(self.url.clone())
+        Some(self.active_url())
+    }
+
+    /// 当前使用的端点
+    fn active_url(&self) -> String {
+        self.endpoints[self.active_endpoint.load(Ordering::Acquire)].clone()
     }
 }
 
@@ -100,57 +458,35 @@ impl TondiGrpcClient {
 #[async_trait]
 impl RpcApi for TondiGrpcClient {
     async fn get_server_info(&self) -> RpcResult<GetServerInfoResponse> {
-        // 尝试重新连接如果未连接
-        if !self.is_connected() {
-            if let Err(e) = self.ensure_connected().await {
-                return Err(tondi_rpc_core::RpcError::General(format!("Not connected: {}", e)));
-            }
-        }
+        let grpc_client = self.active_client().await?;
 
-        // 使用真实的gRPC客户端获取服务器信息
-        if let Some(_grpc_client) = self.grpc_client.lock().unwrap().as_ref() {
-            // 这里需要调用TONDI gRPC客户端的相应方法
-            // 由于TONDI gRPC客户端没有直接实现RpcApi，我们需要适配
-            // 暂时返回默认值，后续需要实现真正的调用
-                        let response = GetServerInfoResponse {
-                            rpc_api_version: 1,
-                            rpc_api_revision: 1,
-                            server_version: "tondi-grpc-client".to_string(),
-                            network_id: RpcNetworkId::from(self.network),
-                            has_utxo_index: false,
-                            is_synced: false,
-                            virtual_daa_score: 0,
-                        };
-                        Ok(response)
-            } else {
-            Err(tondi_rpc_core::RpcError::General("No gRPC client available".to_string()))
+        // 从远程节点获取服务器信息（包括同步状态和虚拟DAA分数）
+        match grpc_client.get_server_info_call(None, tondi_rpc_core::GetServerInfoRequest {}).await {
+            Ok(response) => Ok(response),
+            Err(e) => {
+                log_error!("[TONDI GRPC] 从远程节点获取server info失败: {}", e);
+                Err(tondi_rpc_core::RpcError::General(format!("Failed to get server info from remote node: {}", e)))
+            }
         }
     }
 
     async fn get_connected_peer_info(&self) -> RpcResult<GetConnectedPeerInfoResponse> {
-        // 尝试重新连接如果未连接
-        if !self.is_connected() {
-            if let Err(e) = self.ensure_connected().await {
-                return Err(tondi_rpc_core::RpcError::General(format!("Not connected: {}", e)));
-            }
-        }
+        let grpc_client = self.active_client().await?;
 
-        // 使用真实的gRPC客户端获取对等节点信息
-        if let Some(_grpc_client) = self.grpc_client.lock().unwrap().as_ref() {
-            // 暂时返回空列表，后续需要实现真正的调用
-                let response = GetConnectedPeerInfoResponse::new(vec![]);
-                Ok(response)
-            } else {
-            Err(tondi_rpc_core::RpcError::General("No gRPC client available".to_string()))
+        // 从远程节点获取已连接的对等节点信息
+        match grpc_client.get_connected_peer_info_call(None, tondi_rpc_core::GetConnectedPeerInfoRequest {}).await {
+            Ok(response) => Ok(response),
+            Err(e) => {
+                log_error!("[TONDI GRPC] 从远程节点获取peer info失败: {}", e);
+                Err(tondi_rpc_core::RpcError::General(format!("Failed to get peer info from remote node: {}", e)))
+            }
         }
     }
 
     async fn get_block_count(&self) -> RpcResult<GetBlockCountResponse> {
         // 尝试重新连接如果未连接
-        if !self.is_connected() {
-            if let Err(e) = self.ensure_connected().await {
-                return Err(tondi_rpc_core::RpcError::General(format!("Not connected: {}", e)));
-            }
+        if let Err(e) = self.ensure_connected() {
+            return Err(tondi_rpc_core::RpcError::General(format!("Not connected: {}", e)));
         }
 
         // 使用真实的gRPC客户端获取区块数量
@@ -183,10 +519,8 @@ impl RpcApi for TondiGrpcClient {
 
     async fn get_block_dag_info(&self) -> RpcResult<GetBlockDagInfoResponse> {
         // 尝试重新连接如果未连接
-        if !self.is_connected() {
-            if let Err(e) = self.ensure_connected().await {
-                return Err(tondi_rpc_core::RpcError::General(format!("Not connected: {}", e)));
-            }
+        if let Err(e) = self.ensure_connected() {
+            return Err(tondi_rpc_core::RpcError::General(format!("Not connected: {}", e)));
         }
 
         // 使用真实的gRPC客户端获取区块DAG信息
@@ -221,14 +555,10 @@ impl RpcApi for TondiGrpcClient {
         println!("[TONDI GRPC] get_metrics 被调用，参数: process={}, connection={}, bandwidth={}, consensus={}, storage={}, custom={}", 
             _include_process_metrics, _include_connection_metrics, _include_bandwidth_metrics, _include_consensus_metrics, _include_storage_metrics, _include_custom_metrics);
         println!("[TONDI GRPC] 当前连接状态: is_connected={}", self.is_connected());
-        println!("[TONDI GRPC] 当前URL: {}", self.url);
+        println!("[TONDI GRPC] 当前URL: {}", self.active_url());
 
-        if !self.is_connected() {
-            println!("[TONDI GRPC] 尝试重新连接...");
-            if let Err(e) = self.ensure_connected().await {
-                println!("[TONDI GRPC] 重新连接失败: {}", e);
-                return Err(tondi_rpc_core::RpcError::General(format!("Not connected: {}", e)));
-            }
+        if let Err(e) = self.ensure_connected() {
+            return Err(tondi_rpc_core::RpcError::General(format!("Not connected: {}", e)));
         }
 
         let grpc_client = {
@@ -268,21 +598,12 @@ impl RpcApi for TondiGrpcClient {
     }
 
     // 实现其他必要的方法，返回默认值或错误
-    async fn ping_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, _request: tondi_rpc_core::PingRequest) -> RpcResult<tondi_rpc_core::PingResponse> {
-        Ok(tondi_rpc_core::PingResponse {})
+    async fn ping_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, request: tondi_rpc_core::PingRequest) -> RpcResult<tondi_rpc_core::PingResponse> {
+        self.active_client().await?.ping_call(None, request).await
     }
 
-    async fn get_system_info_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, _request: tondi_rpc_core::GetSystemInfoRequest) -> RpcResult<tondi_rpc_core::GetSystemInfoResponse> {
-        let response = tondi_rpc_core::GetSystemInfoResponse {
-                    version: "tondi-grpc-client".to_string(),
-                    system_id: Some(b"tondi-grpc".to_vec()),
-                    git_hash: None,
-                    total_memory: 0,
-                    cpu_physical_cores: 0,
-                    fd_limit: 0,
-                    proxy_socket_limit_per_cpu_core: Some(0),
-                        };
-                        Ok(response)
+    async fn get_system_info_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, request: tondi_rpc_core::GetSystemInfoRequest) -> RpcResult<tondi_rpc_core::GetSystemInfoResponse> {
+        self.active_client().await?.get_system_info_call(None, request).await
     }
 
     async fn get_connections_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, request: tondi_rpc_core::GetConnectionsRequest) -> RpcResult<tondi_rpc_core::GetConnectionsResponse> {
@@ -310,18 +631,12 @@ impl RpcApi for TondiGrpcClient {
         self.get_server_info().await
     }
 
-    async fn get_sync_status_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, _request: tondi_rpc_core::GetSyncStatusRequest) -> RpcResult<tondi_rpc_core::GetSyncStatusResponse> {
-        let response = tondi_rpc_core::GetSyncStatusResponse {
-            is_synced: false,
-        };
-        Ok(response)
+    async fn get_sync_status_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, request: tondi_rpc_core::GetSyncStatusRequest) -> RpcResult<tondi_rpc_core::GetSyncStatusResponse> {
+        self.active_client().await?.get_sync_status_call(None, request).await
     }
 
-    async fn get_current_network_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, _request: tondi_rpc_core::GetCurrentNetworkRequest) -> RpcResult<tondi_rpc_core::GetCurrentNetworkResponse> {
-        let response = tondi_rpc_core::GetCurrentNetworkResponse {
-            network: tondi_rpc_core::RpcNetworkType::Mainnet,
-                };
-                Ok(response)
+    async fn get_current_network_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, request: tondi_rpc_core::GetCurrentNetworkRequest) -> RpcResult<tondi_rpc_core::GetCurrentNetworkResponse> {
+        self.active_client().await?.get_current_network_call(None, request).await
     }
 
     async fn submit_block_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, _request: tondi_rpc_core::SubmitBlockRequest) -> RpcResult<tondi_rpc_core::SubmitBlockResponse> {
@@ -335,71 +650,80 @@ impl RpcApi for TondiGrpcClient {
         Err(tondi_rpc_core::RpcError::General("gRPC get_block_template_call尚未实现".to_string()))
     }
 
-    async fn get_peer_addresses_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, _request: tondi_rpc_core::GetPeerAddressesRequest) -> RpcResult<tondi_rpc_core::GetPeerAddressesResponse> {
-        let response = tondi_rpc_core::GetPeerAddressesResponse {
-                    known_addresses: vec![],
-                    banned_addresses: vec![],
-        };
-        Ok(response)
+    async fn get_peer_addresses_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, request: tondi_rpc_core::GetPeerAddressesRequest) -> RpcResult<tondi_rpc_core::GetPeerAddressesResponse> {
+        self.active_client().await?.get_peer_addresses_call(None, request).await
     }
 
-    async fn get_sink_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, _request: tondi_rpc_core::GetSinkRequest) -> RpcResult<tondi_rpc_core::GetSinkResponse> {
-        Err(tondi_rpc_core::RpcError::General("gRPC get_sink_call尚未实现".to_string()))
+    async fn get_sink_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, request: tondi_rpc_core::GetSinkRequest) -> RpcResult<tondi_rpc_core::GetSinkResponse> {
+        self.active_client().await?.get_sink_call(None, request).await
     }
 
-    async fn get_mempool_entry_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, _request: tondi_rpc_core::GetMempoolEntryRequest) -> RpcResult<tondi_rpc_core::GetMempoolEntryResponse> {
-        Err(tondi_rpc_core::RpcError::General("gRPC get_mempool_entry_call尚未实现".to_string()))
+    async fn get_mempool_entry_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, request: tondi_rpc_core::GetMempoolEntryRequest) -> RpcResult<tondi_rpc_core::GetMempoolEntryResponse> {
+        self.active_client().await?.get_mempool_entry_call(None, request).await
     }
 
-    async fn get_mempool_entries_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, _request: tondi_rpc_core::GetMempoolEntriesRequest) -> RpcResult<tondi_rpc_core::GetMempoolEntriesResponse> {
-        let response = tondi_rpc_core::GetMempoolEntriesResponse {
-                    mempool_entries: vec![],
-        };
-        Ok(response)
+    async fn get_mempool_entries_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, request: tondi_rpc_core::GetMempoolEntriesRequest) -> RpcResult<tondi_rpc_core::GetMempoolEntriesResponse> {
+        self.active_client().await?.get_mempool_entries_call(None, request).await
     }
 
     async fn get_connected_peer_info_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, _request: tondi_rpc_core::GetConnectedPeerInfoRequest) -> RpcResult<GetConnectedPeerInfoResponse> {
         self.get_connected_peer_info().await
     }
 
-    async fn add_peer_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, _request: tondi_rpc_core::AddPeerRequest) -> RpcResult<tondi_rpc_core::AddPeerResponse> {
-        Err(tondi_rpc_core::RpcError::General("gRPC add_peer_call尚未实现".to_string()))
+    async fn add_peer_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, request: tondi_rpc_core::AddPeerRequest) -> RpcResult<tondi_rpc_core::AddPeerResponse> {
+        self.active_client().await?.add_peer_call(None, request).await
     }
 
-    async fn submit_transaction_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, _request: tondi_rpc_core::SubmitTransactionRequest) -> RpcResult<tondi_rpc_core::SubmitTransactionResponse> {
-        Err(tondi_rpc_core::RpcError::General("gRPC submit_transaction_call尚未实现".to_string()))
+    async fn submit_transaction_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, request: tondi_rpc_core::SubmitTransactionRequest) -> RpcResult<tondi_rpc_core::SubmitTransactionResponse> {
+        let grpc_client = self.active_client().await?;
+
+        // 节点返回的错误（例如交易被拒绝的原因）原样传递给钱包，
+        // 以便在发送界面中显示给用户
+        match grpc_client.submit_transaction_call(None, request).await {
+            Ok(response) => {
+                log_info!("[TONDI GRPC] 交易已提交: {}", response.transaction_id);
+                Ok(response)
+            }
+            Err(e) => {
+                log_error!("[TONDI GRPC] 交易提交失败: {}", e);
+                Err(e)
+            }
+        }
     }
 
-    async fn submit_transaction_replacement_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, _request: tondi_rpc_core::SubmitTransactionReplacementRequest) -> RpcResult<tondi_rpc_core::SubmitTransactionReplacementResponse> {
-        Err(tondi_rpc_core::RpcError::General("gRPC submit_transaction_replacement_call尚未实现".to_string()))
+    async fn submit_transaction_replacement_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, request: tondi_rpc_core::SubmitTransactionReplacementRequest) -> RpcResult<tondi_rpc_core::SubmitTransactionReplacementResponse> {
+        let grpc_client = self.active_client().await?;
+
+        match grpc_client.submit_transaction_replacement_call(None, request).await {
+            Ok(response) => {
+                log_info!("[TONDI GRPC] 替换交易已提交: {}", response.transaction_id);
+                Ok(response)
+            }
+            Err(e) => {
+                log_error!("[TONDI GRPC] 替换交易提交失败: {}", e);
+                Err(e)
+            }
+        }
     }
 
-    async fn get_block_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, _request: tondi_rpc_core::GetBlockRequest) -> RpcResult<tondi_rpc_core::GetBlockResponse> {
-        Err(tondi_rpc_core::RpcError::General("gRPC get_block_call尚未实现".to_string()))
+    async fn get_block_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, request: tondi_rpc_core::GetBlockRequest) -> RpcResult<tondi_rpc_core::GetBlockResponse> {
+        self.active_client().await?.get_block_call(None, request).await
     }
 
     async fn get_subnetwork_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, _request: tondi_rpc_core::GetSubnetworkRequest) -> RpcResult<tondi_rpc_core::GetSubnetworkResponse> {
         Err(tondi_rpc_core::RpcError::General("gRPC get_subnetwork_call尚未实现".to_string()))
     }
 
-    async fn get_virtual_chain_from_block_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, _request: tondi_rpc_core::GetVirtualChainFromBlockRequest) -> RpcResult<tondi_rpc_core::GetVirtualChainFromBlockResponse> {
-        Err(tondi_rpc_core::RpcError::General("gRPC get_virtual_chain_from_block_call尚未实现".to_string()))
+    async fn get_virtual_chain_from_block_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, request: tondi_rpc_core::GetVirtualChainFromBlockRequest) -> RpcResult<tondi_rpc_core::GetVirtualChainFromBlockResponse> {
+        self.active_client().await?.get_virtual_chain_from_block_call(None, request).await
     }
 
-    async fn get_blocks_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, _request: tondi_rpc_core::GetBlocksRequest) -> RpcResult<tondi_rpc_core::GetBlocksResponse> {
-        let response = tondi_rpc_core::GetBlocksResponse {
-            block_hashes: vec![],
-            blocks: vec![],
-        };
-        Ok(response)
+    async fn get_blocks_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, request: tondi_rpc_core::GetBlocksRequest) -> RpcResult<tondi_rpc_core::GetBlocksResponse> {
+        self.active_client().await?.get_blocks_call(None, request).await
     }
 
-    async fn get_block_count_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, _request: tondi_rpc_core::GetBlockCountRequest) -> RpcResult<BlockCount> {
-        let response = BlockCount {
-            header_count: 0,
-            block_count: 0,
-        };
-        Ok(response)
+    async fn get_block_count_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, request: tondi_rpc_core::GetBlockCountRequest) -> RpcResult<BlockCount> {
+        self.active_client().await?.get_block_count_call(None, request).await
     }
 
     async fn get_block_dag_info_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, _request: tondi_rpc_core::GetBlockDagInfoRequest) -> RpcResult<GetBlockDagInfoResponse> {
@@ -414,69 +738,60 @@ impl RpcApi for TondiGrpcClient {
         Err(tondi_rpc_core::RpcError::General("gRPC shutdown_call尚未实现".to_string()))
     }
 
-    async fn get_headers_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, _request: tondi_rpc_core::GetHeadersRequest) -> RpcResult<tondi_rpc_core::GetHeadersResponse> {
-        Err(tondi_rpc_core::RpcError::General("gRPC get_headers_call尚未实现".to_string()))
+    async fn get_headers_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, request: tondi_rpc_core::GetHeadersRequest) -> RpcResult<tondi_rpc_core::GetHeadersResponse> {
+        self.active_client().await?.get_headers_call(None, request).await
     }
 
-    async fn get_balance_by_address_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, _request: tondi_rpc_core::GetBalanceByAddressRequest) -> RpcResult<tondi_rpc_core::GetBalanceByAddressResponse> {
-        Err(tondi_rpc_core::RpcError::General("gRPC get_balance_by_address_call尚未实现".to_string()))
+    async fn get_balance_by_address_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, request: tondi_rpc_core::GetBalanceByAddressRequest) -> RpcResult<tondi_rpc_core::GetBalanceByAddressResponse> {
+        self.active_client().await?.get_balance_by_address_call(None, request).await
     }
 
-    async fn get_balances_by_addresses_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, _request: tondi_rpc_core::GetBalancesByAddressesRequest) -> RpcResult<tondi_rpc_core::GetBalancesByAddressesResponse> {
-        Err(tondi_rpc_core::RpcError::General("gRPC get_balances_by_addresses_call尚未实现".to_string()))
+    async fn get_balances_by_addresses_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, request: tondi_rpc_core::GetBalancesByAddressesRequest) -> RpcResult<tondi_rpc_core::GetBalancesByAddressesResponse> {
+        self.active_client().await?.get_balances_by_addresses_call(None, request).await
     }
 
-    async fn get_utxos_by_addresses_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, _request: tondi_rpc_core::GetUtxosByAddressesRequest) -> RpcResult<tondi_rpc_core::GetUtxosByAddressesResponse> {
-        Err(tondi_rpc_core::RpcError::General("gRPC get_utxos_by_addresses_call尚未实现".to_string()))
+    async fn get_utxos_by_addresses_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, request: tondi_rpc_core::GetUtxosByAddressesRequest) -> RpcResult<tondi_rpc_core::GetUtxosByAddressesResponse> {
+        self.active_client().await?.get_utxos_by_addresses_call(None, request).await
     }
 
-    async fn get_sink_blue_score_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, _request: tondi_rpc_core::GetSinkBlueScoreRequest) -> RpcResult<tondi_rpc_core::GetSinkBlueScoreResponse> {
-        Err(tondi_rpc_core::RpcError::General("gRPC get_sink_blue_score_call尚未实现".to_string()))
+    async fn get_sink_blue_score_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, request: tondi_rpc_core::GetSinkBlueScoreRequest) -> RpcResult<tondi_rpc_core::GetSinkBlueScoreResponse> {
+        self.active_client().await?.get_sink_blue_score_call(None, request).await
     }
 
-    async fn ban_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, _request: tondi_rpc_core::BanRequest) -> RpcResult<tondi_rpc_core::BanResponse> {
-        Err(tondi_rpc_core::RpcError::General("gRPC ban_call尚未实现".to_string()))
+    async fn ban_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, request: tondi_rpc_core::BanRequest) -> RpcResult<tondi_rpc_core::BanResponse> {
+        self.active_client().await?.ban_call(None, request).await
     }
 
-    async fn unban_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, _request: tondi_rpc_core::UnbanRequest) -> RpcResult<tondi_rpc_core::UnbanResponse> {
-        Err(tondi_rpc_core::RpcError::General("gRPC unban_call尚未实现".to_string()))
+    async fn unban_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, request: tondi_rpc_core::UnbanRequest) -> RpcResult<tondi_rpc_core::UnbanResponse> {
+        self.active_client().await?.unban_call(None, request).await
     }
 
-    async fn get_info_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, _request: tondi_rpc_core::GetInfoRequest) -> RpcResult<tondi_rpc_core::GetInfoResponse> {
-        let response = tondi_rpc_core::GetInfoResponse {
-            p2p_id: "tondi-grpc-client".to_string(),
-            mempool_size: 0,
-            server_version: "1.0.0".to_string(),
-            has_message_id: true,
-            has_notify_command: true,
-            is_synced: false,
-            is_utxo_indexed: false,
-        };
-        Ok(response)
+    async fn get_info_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, request: tondi_rpc_core::GetInfoRequest) -> RpcResult<tondi_rpc_core::GetInfoResponse> {
+        self.active_client().await?.get_info_call(None, request).await
     }
 
     async fn estimate_network_hashes_per_second_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, _request: tondi_rpc_core::EstimateNetworkHashesPerSecondRequest) -> RpcResult<tondi_rpc_core::EstimateNetworkHashesPerSecondResponse> {
         Err(tondi_rpc_core::RpcError::General("gRPC estimate_network_hashes_per_second_call尚未实现".to_string()))
     }
 
-    async fn get_mempool_entries_by_addresses_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, _request: tondi_rpc_core::GetMempoolEntriesByAddressesRequest) -> RpcResult<tondi_rpc_core::GetMempoolEntriesByAddressesResponse> {
-        Err(tondi_rpc_core::RpcError::General("gRPC get_mempool_entries_by_addresses_call尚未实现".to_string()))
+    async fn get_mempool_entries_by_addresses_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, request: tondi_rpc_core::GetMempoolEntriesByAddressesRequest) -> RpcResult<tondi_rpc_core::GetMempoolEntriesByAddressesResponse> {
+        self.active_client().await?.get_mempool_entries_by_addresses_call(None, request).await
     }
 
     async fn get_coin_supply_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, _request: tondi_rpc_core::GetCoinSupplyRequest) -> RpcResult<tondi_rpc_core::GetCoinSupplyResponse> {
         Err(tondi_rpc_core::RpcError::General("gRPC get_coin_supply_call尚未实现".to_string()))
     }
 
-    async fn get_daa_score_timestamp_estimate_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, _request: tondi_rpc_core::GetDaaScoreTimestampEstimateRequest) -> RpcResult<tondi_rpc_core::GetDaaScoreTimestampEstimateResponse> {
-        Err(tondi_rpc_core::RpcError::General("gRPC get_daa_score_timestamp_estimate_call尚未实现".to_string()))
+    async fn get_daa_score_timestamp_estimate_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, request: tondi_rpc_core::GetDaaScoreTimestampEstimateRequest) -> RpcResult<tondi_rpc_core::GetDaaScoreTimestampEstimateResponse> {
+        self.active_client().await?.get_daa_score_timestamp_estimate_call(None, request).await
     }
 
-    async fn get_utxo_return_address_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, _request: tondi_rpc_core::GetUtxoReturnAddressRequest) -> RpcResult<tondi_rpc_core::GetUtxoReturnAddressResponse> {
-        Err(tondi_rpc_core::RpcError::General("gRPC get_utxo_return_address_call尚未实现".to_string()))
+    async fn get_utxo_return_address_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, request: tondi_rpc_core::GetUtxoReturnAddressRequest) -> RpcResult<tondi_rpc_core::GetUtxoReturnAddressResponse> {
+        self.active_client().await?.get_utxo_return_address_call(None, request).await
     }
 
-    async fn get_fee_estimate_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, _request: tondi_rpc_core::GetFeeEstimateRequest) -> RpcResult<tondi_rpc_core::GetFeeEstimateResponse> {
-        Err(tondi_rpc_core::RpcError::General("gRPC get_fee_estimate_call尚未实现".to_string()))
+    async fn get_fee_estimate_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, request: tondi_rpc_core::GetFeeEstimateRequest) -> RpcResult<tondi_rpc_core::GetFeeEstimateResponse> {
+        self.active_client().await?.get_fee_estimate_call(None, request).await
     }
 
     async fn get_fee_estimate_experimental_call(&self, _connection: Option<&tondi_rpc_core::api::connection::DynRpcConnection>, _request: tondi_rpc_core::GetFeeEstimateExperimentalRequest) -> RpcResult<tondi_rpc_core::GetFeeEstimateExperimentalResponse> {
@@ -487,19 +802,72 @@ impl RpcApi for TondiGrpcClient {
         Err(tondi_rpc_core::RpcError::General("gRPC get_current_block_color_call尚未实现".to_string()))
     }
 
-    fn register_new_listener(&self, _connection: tondi_rpc_core::notify::connection::ChannelConnection) -> u64 {
-        0
-    }
-
-    async fn unregister_listener(&self, _id: u64) -> RpcResult<()> {
+    fn register_new_listener(&self, connection: ChannelConnection) -> ListenerId {
+        let id = self.next_listener_id.fetch_add(1, Ordering::SeqCst);
+        // 如果当前没有可用的客户端，监听器会在重新连接后注册
+        let inner_id = self
+            .current_client()
+            .ok()
+            .map(|grpc_client| grpc_client.register_new_listener(connection.clone()));
+        self.listeners.lock().unwrap().insert(
+            id,
+            ListenerRecord {
+                connection,
+                inner_id,
+                scopes: vec![],
+            },
+        );
+        id
+    }
+
+    async fn unregister_listener(&self, id: ListenerId) -> RpcResult<()> {
+        let record = self.listeners.lock().unwrap().remove(&id);
+        if let Some(inner_id) = record.and_then(|record| record.inner_id) {
+            if let Ok(grpc_client) = self.current_client() {
+                grpc_client.unregister_listener(inner_id).await?;
+            }
+        }
         Ok(())
     }
 
-    async fn start_notify(&self, _id: u64, _scope: tondi_notify::scope::Scope) -> RpcResult<()> {
-        Ok(())
+    async fn start_notify(&self, id: ListenerId, scope: Scope) -> RpcResult<()> {
+        let inner_id = {
+            let mut listeners = self.listeners.lock().unwrap();
+            let record = listeners
+                .get_mut(&id)
+                .ok_or_else(|| tondi_rpc_core::RpcError::General(format!("Unknown listener id: {}", id)))?;
+            record.add_scope(&scope);
+            record.inner_id
+        };
+
+        match (inner_id, self.current_client()) {
+            (Some(inner_id), Ok(grpc_client)) => grpc_client.start_notify(inner_id, scope).await,
+            // 未连接时订阅已被记录，将在重新连接后恢复
+            _ => Ok(()),
+        }
     }
 
-    async fn stop_notify(&self, _id: u64, _scope: tondi_notify::scope::Scope) -> RpcResult<()> {
-        Ok(())
+    async fn stop_notify(&self, id: ListenerId, scope: Scope) -> RpcResult<()> {
+        let inner_id = {
+            let mut listeners = self.listeners.lock().unwrap();
+            match listeners.get_mut(&id) {
+                Some(record) => {
+                    record.remove_scope(&scope);
+                    record.inner_id
+                }
+                None => return Ok(()),
+            }
+        };
+
+        match (inner_id, self.current_client()) {
+            (Some(inner_id), Ok(grpc_client)) => grpc_client.stop_notify(inner_id, scope).await,
+            _ => Ok(()),
+        }
     }
 }
+
+/// 在等待时间上增加最多25%的随机抖动，避免多个客户端同时重连
+fn with_jitter(delay: Duration) -> Duration {
+    let max_jitter = delay.as_millis() as u64 / 4;
+    delay + Duration::from_millis(rand::thread_rng().gen_range(0..=max_jitter))
+}
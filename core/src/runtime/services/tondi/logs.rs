@@ -1,69 +1,206 @@
 use crate::imports::*;
 
-pub enum Log {
-    Debug(String),
-    Trace(String),
-    Info(String),
-    Warning(String),
-    Error(String),
-    Processed(String),
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warning,
+    Error,
+}
+
+impl LogLevel {
+    pub fn iter() -> impl Iterator<Item = &'static LogLevel> {
+        [
+            LogLevel::Error,
+            LogLevel::Warning,
+            LogLevel::Info,
+            LogLevel::Debug,
+            LogLevel::Trace,
+        ]
+        .iter()
+    }
+
+    fn parse(level: &str) -> Option<Self> {
+        match level.trim().to_ascii_uppercase().as_str() {
+            "TRACE" | "TRC" => Some(LogLevel::Trace),
+            "DEBUG" | "DBG" => Some(LogLevel::Debug),
+            "INFO" | "INF" => Some(LogLevel::Info),
+            "WARN" | "WARNING" | "WRN" => Some(LogLevel::Warning),
+            "ERROR" | "ERR" => Some(LogLevel::Error),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LogLevel::Trace => write!(f, "TRACE"),
+            LogLevel::Debug => write!(f, "DEBUG"),
+            LogLevel::Info => write!(f, "INFO"),
+            LogLevel::Warning => write!(f, "WARN"),
+            LogLevel::Error => write!(f, "ERROR"),
+        }
+    }
+}
+
+/// A single line of the node output, parsed into its components.
+/// Lines that do not follow a known log format are kept as
+/// `Info` messages without a timestamp.
+#[derive(Debug, Clone)]
+pub struct Log {
+    pub timestamp: Option<String>,
+    pub level: LogLevel,
+    pub target: Option<String>,
+    pub message: String,
+    pub line: String,
+}
+
+impl Log {
+    fn unstructured(line: &str) -> Self {
+        Log {
+            timestamp: None,
+            level: LogLevel::Info,
+            target: None,
+            message: line.to_string(),
+            line: line.to_string(),
+        }
+    }
+
+    /// `Processed ...` progress reports of the node
+    pub fn is_processed(&self) -> bool {
+        self.message.starts_with("Processed")
+    }
+
+    /// Time of day portion of the timestamp (`HH:MM:SS.mmm`)
+    pub fn time(&self) -> Option<&str> {
+        let timestamp = self.timestamp.as_deref()?;
+        let time = timestamp.split([' ', 'T']).nth(1).unwrap_or(timestamp);
+        Some(time.get(..12).unwrap_or(time))
+    }
+
+    /// Case-insensitive match of `text` against the target and message
+    pub fn contains(&self, text: &str) -> bool {
+        let text = text.to_lowercase();
+        self.message.to_lowercase().contains(&text)
+            || self.target.as_ref().is_some_and(|target| target.to_lowercase().contains(&text))
+    }
+}
+
+fn is_timestamp(text: &str) -> bool {
+    text.len() >= 8 && text.starts_with(|c: char| c.is_ascii_digit())
+}
+
+/// Split a `module::path: message` prefix from the message
+fn split_target(message: &str) -> (Option<String>, String) {
+    match message.split_once(": ") {
+        Some((target, message))
+            if target.contains("::") && !target.contains(char::is_whitespace) =>
+        {
+            (Some(target.to_string()), message.to_string())
+        }
+        _ => (None, message.to_string()),
+    }
 }
 
 impl From<&str> for Log {
     fn from(line: &str) -> Self {
-        let line = line.trim();
-        if !line.is_empty() {
-            if line.len() < 38 || &line[30..31] != "[" {
-                Log::Info(line.to_string())
-            } else {
-                let time = &line[11..23];
-                let kind = &line[31..36];
-                let text = &line[38..];
-                // text
-                match kind {
-                    "WARN " => Log::Warning(format!("{time} {text}")),
-                    "ERROR" => Log::Error(format!("{time} {text}")),
-                    _ => {
-                        if text.starts_with("Processed") {
-                            Log::Processed(format!("{time} {text}"))
-                        } else {
-                            Log::Info(format!("{time} {text}"))
-                        }
-                    }
-                }
-            }
-        } else {
-            Log::Info(line.to_string())
+        let line = line.trim_end();
+
+        // env_logger format: `[2024-01-01T12:34:56Z INFO  target] message`
+        let env_logger = line.strip_prefix('[').and_then(|line| {
+            let (header, message) = line.split_once("] ")?;
+            let mut parts = header.split_whitespace();
+            let timestamp = parts.next().filter(|timestamp| is_timestamp(timestamp))?;
+            let level = LogLevel::parse(parts.next()?)?;
+            Some((timestamp, level, parts.next(), message))
+        });
+
+        if let Some((timestamp, level, target, message)) = env_logger {
+            return Log {
+                timestamp: Some(timestamp.to_string()),
+                level,
+                target: target.map(String::from),
+                message: message.to_string(),
+                line: line.to_string(),
+            };
+        }
+
+        // node format: `2024-01-01 12:34:56.789+00:00 [INFO ] message`
+        let node = line.split_once(" [").and_then(|(timestamp, rest)| {
+            let (level, message) = rest.split_once(']')?;
+            is_timestamp(timestamp).then_some((timestamp, LogLevel::parse(level)?, message.trim_start()))
+        });
+
+        if let Some((timestamp, level, message)) = node {
+            let (target, message) = split_target(message);
+            return Log {
+                timestamp: Some(timestamp.to_string()),
+                level,
+                target,
+                message,
+                line: line.to_string(),
+            };
         }
+
+        Log::unstructured(line)
     }
 }
 
 impl From<&Log> for RichText {
     fn from(log: &Log) -> Self {
-        let text = match log {
-            Log::Info(text) => RichText::from(text).color(theme_color().logs_info_color),
-            Log::Error(text) => RichText::from(text).color(theme_color().logs_error_color),
-            Log::Warning(text) => RichText::from(text).color(theme_color().logs_warning_color),
-            Log::Debug(text) => RichText::from(text).color(theme_color().logs_debug_color),
-            Log::Trace(text) => RichText::from(text).color(theme_color().logs_trace_color),
-            Log::Processed(text) => RichText::from(text).color(theme_color().logs_processed_color),
+        let text = match log.time() {
+            Some(time) => format!("{time} {}", log.message),
+            None => log.message.clone(),
+        };
+
+        let color = match log.level {
+            LogLevel::Error => theme_color().logs_error_color,
+            LogLevel::Warning => theme_color().logs_warning_color,
+            LogLevel::Debug => theme_color().logs_debug_color,
+            LogLevel::Trace => theme_color().logs_trace_color,
+            LogLevel::Info if log.is_processed() => theme_color().logs_processed_color,
+            LogLevel::Info => theme_color().logs_info_color,
         };
 
-        text.font(FontId::monospace(theme_style().node_log_font_size))
+        RichText::from(text)
+            .color(color)
+            .font(FontId::monospace(theme_style().node_log_font_size))
     }
 }
 
 impl std::fmt::Display for Log {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let text = match self {
-            Log::Info(text) => text.to_owned(),
-            Log::Error(text) => text.to_owned(),
-            Log::Warning(text) => text.to_owned(),
-            Log::Debug(text) => text.to_owned(),
-            Log::Trace(text) => text.to_owned(),
-            Log::Processed(text) => text.to_owned(),
-        };
+        write!(f, "{}", self.line)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_parser() {
+        let log = Log::from("2024-05-01 10:20:30.123+00:00 [WARN ] tondi_p2p_flows::flow_context: Peer disconnected");
+        assert_eq!(log.level, LogLevel::Warning);
+        assert_eq!(log.time(), Some("10:20:30.123"));
+        assert_eq!(log.target.as_deref(), Some("tondi_p2p_flows::flow_context"));
+        assert_eq!(log.message, "Peer disconnected");
+
+        let log = Log::from("2024-05-01 10:20:30.123+00:00 [INFO ] Processed 12 blocks and 30 headers in the last 10.00s");
+        assert_eq!(log.level, LogLevel::Info);
+        assert!(log.target.is_none());
+        assert!(log.is_processed());
+
+        let log = Log::from("[2024-05-01T10:20:30Z ERROR tondid::daemon] Database error");
+        assert_eq!(log.level, LogLevel::Error);
+        assert_eq!(log.target.as_deref(), Some("tondid::daemon"));
+        assert_eq!(log.message, "Database error");
 
-        write!(f, "{}", text)
+        let log = Log::from("thread 'main' panicked at [src/main.rs:1:1]");
+        assert_eq!(log.level, LogLevel::Info);
+        assert!(log.timestamp.is_none());
+        assert_eq!(log.message, "thread 'main' panicked at [src/main.rs:1:1]");
     }
 }
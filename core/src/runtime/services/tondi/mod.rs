@@ -37,6 +37,9 @@ cfg_if! {
         pub mod inproc;
         pub mod logs;
         use logs::Log;
+        pub mod supervisor;
+        use supervisor::{DaemonCrash, Supervisor};
+        use std::io::Write;
         pub use tondid_lib::args::Args;
 
         #[async_trait]
@@ -53,6 +56,13 @@ cfg_if! {
             StartExternalAsDaemon { path: PathBuf, config: Config, network : Network },
             StartRemoteConnection { rpc_config : RpcConfig, network : Network },
             Stdout { line : String },
+            /// The daemon process exited without being stopped
+            DaemonExit { code : Option<i32> },
+            /// Supervised restart of the daemon started in `generation`
+            RestartDaemon { generation : usize },
+            /// Remove the node database in `path`, stopping the node first
+            /// and restarting it with `node_settings` afterwards if provided
+            ResetDatabase { network : Network, path : PathBuf, node_settings : Option<Box<NodeSettings>> },
             Disable { network : Network },
             Exit,
         }
@@ -82,6 +92,21 @@ cfg_if! {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
 pub struct Context {}
 
+/// Progress of a graceful shutdown of the integrated daemon
+#[derive(Debug, Clone)]
+pub struct DaemonShutdown {
+    pub elapsed: Duration,
+    pub timeout: Duration,
+}
+
+/// Number of log lines reported when the daemon exits unexpectedly
+#[cfg(not(target_arch = "wasm32"))]
+const DAEMON_EXIT_LOG_LINES: usize = 8;
+
+/// Database folder within the network data folder of the node
+#[cfg(not(target_arch = "wasm32"))]
+pub const NODE_DATABASE_FOLDER: &str = "datadir";
+
 pub struct TondiService {
     pub application_events: ApplicationEventsChannel,
     pub service_events: Channel<TondidServiceEvents>,
@@ -93,6 +118,19 @@ pub struct TondiService {
     pub tondid: Mutex<Option<Arc<dyn Tondid + Send + Sync + 'static>>>,
     #[cfg(not(target_arch = "wasm32"))]
     pub logs: Mutex<Vec<Log>>,
+    #[cfg(not(target_arch = "wasm32"))]
+    pub log_tail: Mutex<Option<(PathBuf, std::fs::File)>>,
+    #[cfg(not(target_arch = "wasm32"))]
+    pub supervisor: Mutex<Supervisor>,
+    /// Event that started the current daemon, replayed on restart
+    #[cfg(not(target_arch = "wasm32"))]
+    pub daemon_start: Mutex<Option<TondidServiceEvents>>,
+    /// Incremented whenever the node is started or stopped by the user,
+    /// invalidating any pending supervised restart
+    #[cfg(not(target_arch = "wasm32"))]
+    pub daemon_generation: AtomicUsize,
+    #[cfg(not(target_arch = "wasm32"))]
+    pub daemon_restart: AtomicBool,
     pub connect_on_startup: Option<NodeSettings>,
 }
 
@@ -125,14 +163,7 @@ impl TondiService {
         }
 
         Self {
-            // 对于devnet，总是尝试启动连接
-            connect_on_startup: if settings.node.network == Network::Devnet {
-                // 对于devnet，使用配置中的设置
-                Some(settings.node.clone())
-            } else {
-                // 对于其他网络，使用原有逻辑
-                settings.initialized.then(|| settings.node.clone())
-            },
+            connect_on_startup: settings.initialized.then(|| settings.node.clone()),
             application_events,
             service_events,
             task_ctl: Channel::oneshot(),
@@ -143,6 +174,16 @@ impl TondiService {
             tondid: Mutex::new(None),
             #[cfg(not(target_arch = "wasm32"))]
             logs: Mutex::new(Vec::new()),
+            #[cfg(not(target_arch = "wasm32"))]
+            log_tail: Mutex::new(None),
+            #[cfg(not(target_arch = "wasm32"))]
+            supervisor: Mutex::new(Supervisor::default()),
+            #[cfg(not(target_arch = "wasm32"))]
+            daemon_start: Mutex::new(None),
+            #[cfg(not(target_arch = "wasm32"))]
+            daemon_generation: AtomicUsize::new(0),
+            #[cfg(not(target_arch = "wasm32"))]
+            daemon_restart: AtomicBool::new(false),
         }
     }
 
@@ -176,7 +217,7 @@ impl TondiService {
         
         // 打印调用栈信息（简化版本）
         println!("[TONDI SERVICE] 调用来源检查...");
-        if let RpcConfig::Grpc { url: Some(net_config) } = config {
+        if let RpcConfig::Grpc { url: Some(net_config), .. } = config {
             println!("[TONDI SERVICE] NetworkInterfaceConfig详细信息:");
             println!("[TONDI SERVICE]   kind: {:?}", net_config.kind);
             println!("[TONDI SERVICE]   custom: {:?}", net_config.custom);
@@ -219,19 +260,17 @@ impl TondiService {
                 let rpc_api: Arc<DynRpcApi> = wrpc_client;
                 Ok(Rpc::new(rpc_api, rpc_ctl))
             }
-            RpcConfig::Grpc { url } => {
+            RpcConfig::Grpc { url, failover } => {
                 println!("[TONDI SERVICE] 使用gRPC配置");
                 cfg_if! {
                     if #[cfg(not(target_arch = "wasm32"))] {
                         // Desktop version: supports gRPC
                         if let Some(network_interface) = url {
                             println!("[TONDI SERVICE] 使用指定的网络接口: {:?}", network_interface);
-                            let grpc_client = TondiGrpcClient::connect(network_interface.clone(), network).await?;
+                            let endpoints = std::iter::once(network_interface.clone()).chain(failover.iter().cloned()).collect();
+                            let grpc_client = TondiGrpcClient::connect_with_failover(endpoints, network).await?;
+                            let rpc_ctl = grpc_client.ctl().clone();
                             let rpc_api: Arc<DynRpcApi> = Arc::new(grpc_client);
-                            let rpc_ctl = RpcCtl::new();
-                            // Set gRPC URL descriptor
-                            let address: ContextualNetAddress = network_interface.clone().into();
-                            rpc_ctl.set_descriptor(Some(format!("grpc://{}", address)));
                             println!("[TONDI SERVICE] gRPC客户端创建成功");
                             Ok(Rpc::new(rpc_api, rpc_ctl))
                         } else {
@@ -240,8 +279,8 @@ impl TondiService {
                             let default_interface = NetworkInterfaceConfig::default();
                             println!("[TONDI SERVICE] 默认接口: {:?}", default_interface);
                             let grpc_client = TondiGrpcClient::connect(default_interface.clone(), network).await?;
+                            let rpc_ctl = grpc_client.ctl().clone();
                             let rpc_api: Arc<DynRpcApi> = Arc::new(grpc_client);
-                            let rpc_ctl = RpcCtl::new();
                             // Set default gRPC URL descriptor
                             let address: ContextualNetAddress = default_interface.into();
                             rpc_ctl.set_descriptor(Some(format!("grpc://{}", address)));
@@ -279,9 +318,16 @@ impl TondiService {
                         .is_ok()
                     {
                         wallet.rpc_ctl().signal_open().await?;
-                    } else if let Ok(_grpc_client) = wallet.rpc_api().clone().downcast_arc::<TondiGrpcClient>() {
-                        // gRPC客户端已经在创建时连接，这里不需要额外的连接步骤
-                        println!("[TONDI SERVICE] gRPC客户端已连接，无需额外连接步骤");
+                    } else if let Ok(grpc_client) = wallet.rpc_api().clone().downcast_arc::<TondiGrpcClient>() {
+                        // gRPC客户端已经在创建时连接，这里通知钱包连接已打开，
+                        // 以便钱包注册通知监听器（UTXO变化等）并发出Connect事件
+                        if grpc_client.is_connected() {
+                            wallet.rpc_ctl().signal_open().await?;
+                        } else {
+                            println!("[TONDI SERVICE] gRPC客户端尚未连接，等待后台重新连接");
+                        }
+                        // 之后的连接状态变化由后台任务通过RpcCtl通知钱包
+                        grpc_client.start_monitor();
                     } else {
                         unimplemented!("connect_rpc_client(): RPC client is not supported")
                     }
@@ -304,6 +350,73 @@ impl TondiService {
         self.logs.lock().unwrap()
     }
 
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn supervisor(&self) -> MutexGuard<'_, Supervisor> {
+        self.supervisor.lock().unwrap()
+    }
+
+    /// Track the daemon started (or stopped) by `event` so that it
+    /// can be restarted by the supervisor if it exits unexpectedly.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn supervise(&self, event: &TondidServiceEvents) {
+        let is_daemon = match event {
+            TondidServiceEvents::StartInternalAsDaemon { .. }
+            | TondidServiceEvents::StartInternalAsPassiveSync { .. }
+            | TondidServiceEvents::StartExternalAsDaemon { .. } => true,
+            TondidServiceEvents::StartInternalInProc { .. }
+            | TondidServiceEvents::StartRemoteConnection { .. }
+            | TondidServiceEvents::Disable { .. } => false,
+            // a database reset does not change the supervised daemon; if the
+            // node is restarted, the resulting start event re-registers it
+            _ => return,
+        };
+
+        if self.daemon_restart.swap(false, Ordering::SeqCst) {
+            self.supervisor().started();
+        } else {
+            self.daemon_generation.fetch_add(1, Ordering::SeqCst);
+            self.supervisor().reset();
+        }
+
+        *self.daemon_start.lock().unwrap() = is_daemon.then(|| event.clone());
+    }
+
+    /// Last `lines` lines of the node output
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn logs_tail(&self, lines: usize) -> Vec<String> {
+        let logs = self.logs.lock().unwrap();
+        logs[logs.len().saturating_sub(lines)..].iter().map(|log| log.to_string()).collect()
+    }
+
+    /// Write the current log buffer to `path`
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save_logs(&self, path: &std::path::Path) -> Result<()> {
+        let logs = self.logs.lock().unwrap().iter().map(|log| log.to_string()).collect::<Vec<_>>();
+        std::fs::write(path, logs.join("\n") + "\n")?;
+        Ok(())
+    }
+
+    /// Save the current log buffer to `path` and keep appending
+    /// node output to it until [`stop_log_tail`](Self::stop_log_tail)
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn start_log_tail(&self, path: PathBuf) -> Result<()> {
+        self.save_logs(&path)?;
+        let file = std::fs::OpenOptions::new().append(true).open(&path)?;
+        self.log_tail.lock().unwrap().replace((path, file));
+        Ok(())
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn stop_log_tail(&self) {
+        self.log_tail.lock().unwrap().take();
+    }
+
+    /// Path of the file receiving the live log tail
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn log_tail(&self) -> Option<PathBuf> {
+        self.log_tail.lock().unwrap().as_ref().map(|(path, _)| path.clone())
+    }
+
     #[cfg(not(target_arch = "wasm32"))]
     pub async fn update_logs(&self, line: String) {
         {
@@ -314,6 +427,16 @@ impl TondiService {
             logs.push(line.as_str().into());
         }
 
+        {
+            let mut log_tail = self.log_tail.lock().unwrap();
+            if let Some((path, file)) = log_tail.as_mut() {
+                if let Err(err) = writeln!(file, "{}", line.trim_end()) {
+                    log_error!("Unable to write log tail to {}: {err}", path.display());
+                    log_tail.take();
+                }
+            }
+        }
+
         if update_logs_flag().load(Ordering::SeqCst) {
             self.application_events
                 .sender
@@ -383,6 +506,8 @@ impl TondiService {
         if let Some(wallet) = self.core_wallet() {
             if let Ok(wrpc_client) = wallet.rpc_api().clone().downcast_arc::<TondiRpcClient>() {
                 wrpc_client.disconnect().await?;
+            } else if let Ok(grpc_client) = wallet.rpc_api().clone().downcast_arc::<TondiGrpcClient>() {
+                grpc_client.disconnect().await?;
             } else {
                 wallet.rpc_ctl().signal_close().await?;
             }
@@ -490,12 +615,11 @@ impl TondiService {
             cfg_if! {
                 if #[cfg(not(target_arch = "wasm32"))] {
                     // 桌面版本：尝试使用gRPC配置
-                    let default_net_config = NetworkInterfaceConfig::default();
-                    println!("[CALL SITE 481 DEBUG] NetworkInterfaceConfig::default(): {:?}", default_net_config);
+                    let default_net_config = network_defaults().get(network).grpc_interface();
                     let grpc_config = RpcConfig::Grpc {
                         url: Some(default_net_config),
+                        failover: Vec::new(),
                     };
-                                            println!("[CALL SITE 481] 调用 create_rpc_client");
                         match Self::create_rpc_client(&grpc_config, network).await {
                             Ok(grpc_rpc) => {
                                 let rpc_api = grpc_rpc.rpc_api().clone();
@@ -517,7 +641,7 @@ impl TondiService {
                                     println!("[TONDI SERVICE DEBUG] 手动触发 CoreWallet::Connect 事件");
                                     self.core_wallet_notify(CoreWalletEvents::Connect {
                                         network_id: network.into(),
-                                        url: Some("grpc://8.210.45.192:16610".to_string()),
+                                        url: Some(format!("grpc://{}", default_net_config)),
                                     }).unwrap();
                                     println!("[TONDI SERVICE DEBUG] CoreWallet::Connect 事件已发送");
                                 } else {
@@ -563,22 +687,41 @@ impl TondiService {
     }
 
     pub fn update_services(&self, node_settings: &NodeSettings, options: Option<RpcOptions>) {
-        match TondidServiceEvents::from_node_settings(node_settings, options) {
-            Ok(event) => {
-                self.service_events
-                    .sender
-                    .try_send(event)
-                    .unwrap_or_else(|err| {
-                        println!("TondidService error: {}", err);
-                    });
-            }
-            Err(err) => {
-                println!("TondidServiceEvents::try_from() error: {}", err);
-            }
+        if let Err(err) = self.try_update_services(node_settings, options) {
+            println!("TondidService error: {}", err);
         }
     }
 
-    fn network(&self) -> Network {
+    /// Convert `node_settings` into a single service event and dispatch it.
+    /// The settings are validated before anything is sent, so a switch
+    /// (e.g. to a node profile) either happens as a whole or not at all.
+    pub fn try_update_services(&self, node_settings: &NodeSettings, options: Option<RpcOptions>) -> Result<()> {
+        let event = TondidServiceEvents::from_node_settings(node_settings, options)?;
+        self.service_events
+            .sender
+            .try_send(event)
+            .map_err(|err| Error::custom(format!("TondidService error: {err}")))
+    }
+
+    /// Erase the database of `network` located in the node data folder
+    /// `path`; a node running on that network is restarted with
+    /// `node_settings` and resynchronizes from scratch.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn reset_database(&self, network: Network, path: PathBuf, node_settings: Option<NodeSettings>) {
+        let event = TondidServiceEvents::ResetDatabase {
+            network,
+            path,
+            node_settings: node_settings.map(Box::new),
+        };
+        self.service_events
+            .sender
+            .try_send(event)
+            .unwrap_or_else(|err| {
+                log_error!("TondidService error: {}", err);
+            });
+    }
+
+    pub fn network(&self) -> Network {
         *self.network.lock().unwrap()
     }
 
@@ -620,6 +763,9 @@ impl TondiService {
     }
 
     async fn handle_event(self: &Arc<Self>, event: TondidServiceEvents) -> Result<bool> {
+        #[cfg(not(target_arch = "wasm32"))]
+        self.supervise(&event);
+
         match event {
             #[cfg(not(target_arch = "wasm32"))]
             TondidServiceEvents::Stdout { line } => {
@@ -635,6 +781,61 @@ impl TondiService {
                 self.update_logs(line).await;
             }
 
+            #[cfg(not(target_arch = "wasm32"))]
+            TondidServiceEvents::DaemonExit { code } => {
+                let log_tail = self.logs_tail(DAEMON_EXIT_LOG_LINES);
+                let code_text = code.map(|code| code.to_string()).unwrap_or_else(|| i18n("none").to_string());
+                log_error!("tondid exited unexpectedly (exit code {code_text}):\n{}", log_tail.join("\n"));
+
+                let message = i18n_args("The node exited unexpectedly (exit code {code})", &[("code", code_text.as_str())]);
+                runtime().notify(UserNotification::error(format!("{message}\n{}", log_tail.join("\n"))));
+
+                let crash = DaemonCrash {
+                    timestamp: unixtime_as_millis_f64(),
+                    code,
+                    log_tail,
+                };
+
+                let restart = self.supervisor().crashed(crash);
+                if let Some(delay) = restart {
+                    let generation = self.daemon_generation.load(Ordering::SeqCst);
+                    let sender = self.service_events.sender.clone();
+                    tokio::spawn(async move {
+                        tokio::time::sleep(delay).await;
+                        sender.send(TondidServiceEvents::RestartDaemon { generation }).await.ok();
+                    });
+                    runtime().notify(UserNotification::warning(i18n_args(
+                        "Restarting the node in {seconds} sec",
+                        &[("seconds", delay.as_secs().to_string().as_str())],
+                    )));
+                } else {
+                    runtime().notify(UserNotification::error(i18n_args(
+                        "The node has crashed {count} times in {minutes} minutes and will not be restarted automatically",
+                        &[
+                            ("count", supervisor::MAX_RESTARTS.to_string().as_str()),
+                            ("minutes", (supervisor::RESTART_BUDGET_WINDOW.as_secs() / 60).to_string().as_str()),
+                        ],
+                    )));
+                }
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            TondidServiceEvents::RestartDaemon { generation } => {
+                if generation == self.daemon_generation.load(Ordering::SeqCst) {
+                    let event = self.daemon_start.lock().unwrap().clone();
+                    if let Some(event) = event {
+                        log_info!("Restarting tondid");
+                        self.daemon_restart.store(true, Ordering::SeqCst);
+                        self.service_events
+                            .sender
+                            .try_send(event)
+                            .unwrap_or_else(|err| {
+                                log_error!("TondidService error: {}", err);
+                            });
+                    }
+                }
+            }
+
             #[cfg(not(target_arch = "wasm32"))]
             TondidServiceEvents::StartInternalInProc { config, network } => {
                 self.stop_all_services().await?;
@@ -658,16 +859,13 @@ impl TondiService {
                 // 尝试使用gRPC连接到本地节点
                 let grpc_config = RpcConfig::Grpc {
                     url: Some(config_clone.grpc_network_interface.clone()),
+                    failover: Vec::new(),
                 };
                 
                 match Self::create_rpc_client(&grpc_config, network).await {
                     Ok(grpc_rpc) => {
                         println!("[TONDI] 成功连接到本地gRPC端点");
-                        let rpc_api = grpc_rpc.rpc_api().clone();
-                        let rpc_ctl = RpcCtl::new();
-                        let rpc = Rpc::new(rpc_api, rpc_ctl.clone());
-
-                        self.start_all_services(Some(rpc), network).await?;
+                        self.start_all_services(Some(grpc_rpc), network).await?;
                         self.connect_rpc_client().await?;
                         
                         // 等待一下让服务完全启动
@@ -704,24 +902,10 @@ impl TondiService {
                 self.retain(tondid.clone());
                 tondid.clone().start(config).await.unwrap();
 
-                // 根据网络类型动态选择RPC配置
-                let rpc_config = if network == Network::Devnet {
-                    // 对于devnet，优先使用gRPC配置
-                    println!("[TONDI SERVICE] Devnet模式，使用gRPC配置");
-                    RpcConfig::Grpc {
-                        url: Some(NetworkInterfaceConfig {
-                            kind: NetworkInterfaceKind::Custom,
-                            custom: "8.210.45.192:16610".parse().unwrap(),
-                        }),
-                    }
-                } else {
-                    // 对于其他网络，使用wRPC配置
-                    println!("[TONDI SERVICE] 使用wRPC配置");
-                    RpcConfig::Wrpc {
-                        url: Some("127.0.0.1".to_string()),
-                        encoding: WrpcEncoding::Borsh,
-                        resolver_urls: None,
-                    }
+                let rpc_config = RpcConfig::Wrpc {
+                    url: Some("127.0.0.1".to_string()),
+                    encoding: WrpcEncoding::Borsh,
+                    resolver_urls: None,
                 };
 
                 let rpc = Self::create_rpc_client(&rpc_config, network).await
@@ -741,24 +925,10 @@ impl TondiService {
                 self.retain(tondid.clone());
                 tondid.clone().start(config).await.unwrap();
 
-                // 根据网络类型动态选择RPC配置
-                let rpc_config = if network == Network::Devnet {
-                    // 对于devnet，优先使用gRPC配置
-                    println!("[TONDI SERVICE] Devnet模式，使用gRPC配置");
-                    RpcConfig::Grpc {
-                        url: Some(NetworkInterfaceConfig {
-                            kind: NetworkInterfaceKind::Custom,
-                            custom: "8.210.45.192:16610".parse().unwrap(),
-                        }),
-                    }
-                } else {
-                    // 对于其他网络，使用wRPC配置
-                    println!("[TONDI SERVICE] 使用wRPC配置");
-                    RpcConfig::Wrpc {
-                        url: None,
-                        encoding: WrpcEncoding::Borsh,
-                        resolver_urls: None,
-                    }
+                let rpc_config = RpcConfig::Wrpc {
+                    url: None,
+                    encoding: WrpcEncoding::Borsh,
+                    resolver_urls: None,
                 };
 
                 let rpc = Self::create_rpc_client(&rpc_config, network).await
@@ -783,24 +953,10 @@ impl TondiService {
 
                 tondid.clone().start(config).await.unwrap();
 
-                // 根据网络类型动态选择RPC配置
-                let rpc_config = if network == Network::Devnet {
-                    // 对于devnet，优先使用gRPC配置
-                    println!("[TONDI SERVICE] Devnet模式，使用gRPC配置");
-                    RpcConfig::Grpc {
-                        url: Some(NetworkInterfaceConfig {
-                            kind: NetworkInterfaceKind::Custom,
-                            custom: "8.210.45.192:16610".parse().unwrap(),
-                        }),
-                    }
-                } else {
-                    // 对于其他网络，使用wRPC配置
-                    println!("[TONDI SERVICE] 使用wRPC配置");
-                    RpcConfig::Wrpc {
-                        url: None,
-                        encoding: WrpcEncoding::Borsh,
-                        resolver_urls: None,
-                    }
+                let rpc_config = RpcConfig::Wrpc {
+                    url: None,
+                    encoding: WrpcEncoding::Borsh,
+                    resolver_urls: None,
                 };
 
                 let rpc = Self::create_rpc_client(&rpc_config, network).await
@@ -834,6 +990,46 @@ impl TondiService {
                 }
             }
 
+            #[cfg(not(target_arch = "wasm32"))]
+            TondidServiceEvents::ResetDatabase { network, path, node_settings } => {
+                if node_settings.is_some() {
+                    self.stop_all_services().await?;
+                }
+
+                // erasing a large database takes a while, so keep it off the service event loop
+                let this = self.clone();
+                tokio::spawn(async move {
+                    let datadir = path.join(NODE_DATABASE_FOLDER);
+                    let result = tokio::task::spawn_blocking({
+                        let datadir = datadir.clone();
+                        move || std::fs::remove_dir_all(datadir)
+                    })
+                    .await
+                    .map_err(std::io::Error::other)
+                    .and_then(|result| result);
+
+                    match result {
+                        Ok(()) => {
+                            log_info!("Removed node database: {}", datadir.display());
+                            runtime().notify(UserNotification::success(i18n_args(
+                                "{network} database has been reset",
+                                &[("network", network.to_string().to_uppercase().as_str())],
+                            )));
+                        }
+                        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+                        Err(err) => {
+                            runtime().error(format!("{}: {err}", i18n("Unable to reset the node database")));
+                        }
+                    }
+
+                    if let Some(node_settings) = node_settings {
+                        this.update_services(&node_settings, None);
+                    }
+
+                    this.update_storage();
+                });
+            }
+
             TondidServiceEvents::Disable { network } => {
                 if let Some(wallet) = self.core_wallet() {
                     self.stop_all_services().await?;
@@ -1044,14 +1240,11 @@ impl Service for TondiService {
                         if #[cfg(not(target_arch = "wasm32"))] {
                             match Network::default() {
                                 Network::Devnet => {
-                                    println!("[TONDI] Devnet模式，尝试连接远程节点...");
-                                    // 对于devnet，使用远程连接配置
+                                    println!("[TONDI] Devnet模式，尝试连接默认节点...");
                                     let event = TondidServiceEvents::StartRemoteConnection { 
                                         rpc_config: RpcConfig::Grpc {
-                                            url: Some(NetworkInterfaceConfig {
-                                                kind: NetworkInterfaceKind::Custom,
-                                                custom: "8.210.45.192:16610".parse().unwrap(),
-                                            }),
+                                            url: Some(network_defaults().get(Network::Devnet).grpc_interface()),
+                                            failover: Vec::new(),
                                         },
                                         network: Network::Devnet 
                                     };
@@ -0,0 +1,122 @@
+use crate::imports::*;
+use std::collections::VecDeque;
+
+/// Maximum number of automatic restarts within [`RESTART_BUDGET_WINDOW`]
+pub const MAX_RESTARTS: usize = 5;
+/// Period over which restarts are counted against [`MAX_RESTARTS`]
+pub const RESTART_BUDGET_WINDOW: Duration = Duration::from_secs(15 * 60);
+const RESTART_BACKOFF_BASE: Duration = Duration::from_secs(2);
+const RESTART_BACKOFF_MAX: Duration = Duration::from_secs(120);
+const CRASH_HISTORY_LENGTH: usize = 16;
+
+/// Record of an unexpected daemon exit
+#[derive(Debug, Clone)]
+pub struct DaemonCrash {
+    /// Unix time in milliseconds
+    pub timestamp: f64,
+    pub code: Option<i32>,
+    pub log_tail: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub enum SupervisorState {
+    #[default]
+    Idle,
+    /// A restart is scheduled after `delay`
+    Restarting { attempt: usize, delay: Duration },
+    /// The restart budget has been exhausted
+    GaveUp,
+}
+
+/// Tracks crashes of the daemon child process and decides
+/// whether and when it should be restarted.
+#[derive(Default)]
+pub struct Supervisor {
+    state: SupervisorState,
+    crashes: VecDeque<DaemonCrash>,
+    restarts: VecDeque<Instant>,
+}
+
+impl Supervisor {
+    pub fn state(&self) -> &SupervisorState {
+        &self.state
+    }
+
+    pub fn crashes(&self) -> impl Iterator<Item = &DaemonCrash> {
+        self.crashes.iter()
+    }
+
+    pub fn clear(&mut self) {
+        self.crashes.clear();
+        if self.state == SupervisorState::GaveUp {
+            self.state = SupervisorState::Idle;
+        }
+    }
+
+    /// The daemon has been (re)started
+    pub fn started(&mut self) {
+        if matches!(self.state, SupervisorState::Restarting { .. }) {
+            self.state = SupervisorState::Idle;
+        }
+    }
+
+    /// The daemon has been stopped or replaced by the user;
+    /// resets the restart budget.
+    pub fn reset(&mut self) {
+        self.state = SupervisorState::Idle;
+        self.restarts.clear();
+    }
+
+    /// Record a crash, returning the delay after which the daemon
+    /// should be restarted or `None` if the restart budget is exhausted.
+    pub fn crashed(&mut self, crash: DaemonCrash) -> Option<Duration> {
+        self.crashes.push_back(crash);
+        while self.crashes.len() > CRASH_HISTORY_LENGTH {
+            self.crashes.pop_front();
+        }
+
+        while self.restarts.front().is_some_and(|instant| instant.elapsed() > RESTART_BUDGET_WINDOW) {
+            self.restarts.pop_front();
+        }
+
+        if self.restarts.len() >= MAX_RESTARTS {
+            self.state = SupervisorState::GaveUp;
+            return None;
+        }
+
+        let attempt = self.restarts.len() + 1;
+        let delay = RESTART_BACKOFF_BASE
+            .saturating_mul(1 << self.restarts.len())
+            .min(RESTART_BACKOFF_MAX);
+        self.restarts.push_back(Instant::now());
+        self.state = SupervisorState::Restarting { attempt, delay };
+        Some(delay)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_supervisor_backoff_and_budget() {
+        let mut supervisor = Supervisor::default();
+        let crash = || DaemonCrash {
+            timestamp: 0.0,
+            code: Some(1),
+            log_tail: vec![],
+        };
+
+        let delays = (0..MAX_RESTARTS)
+            .map(|_| supervisor.crashed(crash()).unwrap().as_secs())
+            .collect::<Vec<_>>();
+        assert_eq!(delays, vec![2, 4, 8, 16, 32]);
+
+        assert_eq!(supervisor.crashed(crash()), None);
+        assert_eq!(supervisor.state(), &SupervisorState::GaveUp);
+        assert_eq!(supervisor.crashes().count(), MAX_RESTARTS + 1);
+
+        supervisor.reset();
+        assert_eq!(supervisor.crashed(crash()).map(|delay| delay.as_secs()), Some(2));
+    }
+}
@@ -1,4 +1,5 @@
 use crate::imports::*;
+use std::collections::BTreeMap;
 use tondi_metrics_core::Metric;
 use tondi_utils::networking::ContextualNetAddress;
 use tondi_wallet_core::storage::local::storage::Storage;
@@ -102,6 +103,22 @@ impl TondidNodeKind {
         }
     }
 
+    /// Node runs as a child process of the application
+    pub fn is_daemon(&self) -> bool {
+        match self {
+            TondidNodeKind::Disable => false,
+            TondidNodeKind::Remote => false,
+            #[cfg(not(target_arch = "wasm32"))]
+            TondidNodeKind::IntegratedInProc => false,
+            #[cfg(not(target_arch = "wasm32"))]
+            TondidNodeKind::IntegratedAsDaemon => true,
+            #[cfg(not(target_arch = "wasm32"))]
+            TondidNodeKind::IntegratedAsPassiveSync => true,
+            #[cfg(not(target_arch = "wasm32"))]
+            TondidNodeKind::ExternalAsDaemon => true,
+        }
+    }
+
     pub fn is_local(&self) -> bool {
         match self {
             TondidNodeKind::Disable => false,
@@ -151,6 +168,9 @@ pub enum RpcConfig {
     },
     Grpc {
         url: Option<NetworkInterfaceConfig>,
+        /// Endpoints to fail over to, in order of preference
+        #[serde(default)]
+        failover: Vec<NetworkInterfaceConfig>,
     },
 }
 
@@ -214,6 +234,129 @@ impl std::fmt::Display for NetworkInterfaceConfig {
     }
 }
 
+/// Default RPC endpoints of a single network.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct NetworkDefaults {
+    pub grpc: ContextualNetAddress,
+    pub wrpc_borsh: ContextualNetAddress,
+    pub wrpc_json: ContextualNetAddress,
+}
+
+impl NetworkDefaults {
+    fn localhost(grpc: u16, wrpc_borsh: u16, wrpc_json: u16) -> Self {
+        let address = |port: u16| format!("127.0.0.1:{port}").parse().unwrap();
+        Self {
+            grpc: address(grpc),
+            wrpc_borsh: address(wrpc_borsh),
+            wrpc_json: address(wrpc_json),
+        }
+    }
+
+    fn mainnet() -> Self {
+        Self::localhost(16110, 17110, 18110)
+    }
+
+    fn testnet() -> Self {
+        Self::localhost(16210, 17210, 18210)
+    }
+
+    fn devnet() -> Self {
+        Self::localhost(16610, 17610, 18610)
+    }
+
+    fn interface(custom: &ContextualNetAddress) -> NetworkInterfaceConfig {
+        NetworkInterfaceConfig {
+            kind: NetworkInterfaceKind::Custom,
+            custom: custom.clone(),
+        }
+    }
+
+    pub fn grpc_interface(&self) -> NetworkInterfaceConfig {
+        Self::interface(&self.grpc)
+    }
+
+    pub fn wrpc_borsh_interface(&self) -> NetworkInterfaceConfig {
+        Self::interface(&self.wrpc_borsh)
+    }
+
+    pub fn wrpc_json_interface(&self) -> NetworkInterfaceConfig {
+        Self::interface(&self.wrpc_json)
+    }
+}
+
+const NETWORK_DEFAULTS_FILENAME: &str = "tondi-ng.networks.toml";
+
+/// Per-network default endpoints. The built-in localhost defaults
+/// can be overridden by a `tondi-ng.networks.toml` file placed
+/// beside the settings file, for example:
+///
+/// ```toml
+/// [devnet]
+/// grpc = "10.0.0.5:16610"
+/// wrpc-borsh = "10.0.0.5:17610"
+/// wrpc-json = "10.0.0.5:18610"
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct NetworkDefaultsTable {
+    #[serde(default = "NetworkDefaults::mainnet")]
+    pub mainnet: NetworkDefaults,
+    #[serde(default = "NetworkDefaults::testnet")]
+    pub testnet: NetworkDefaults,
+    #[serde(default = "NetworkDefaults::devnet")]
+    pub devnet: NetworkDefaults,
+}
+
+impl Default for NetworkDefaultsTable {
+    fn default() -> Self {
+        Self {
+            mainnet: NetworkDefaults::mainnet(),
+            testnet: NetworkDefaults::testnet(),
+            devnet: NetworkDefaults::devnet(),
+        }
+    }
+}
+
+impl NetworkDefaultsTable {
+    pub fn get(&self, network: Network) -> &NetworkDefaults {
+        match network {
+            Network::Mainnet => &self.mainnet,
+            Network::Testnet => &self.testnet,
+            Network::Devnet => &self.devnet,
+        }
+    }
+
+    /// Check if the address is one of the defaults of any network,
+    /// i.e. it has not been customized by the user.
+    pub fn is_default(&self, address: &ContextualNetAddress) -> bool {
+        [&self.mainnet, &self.testnet, &self.devnet]
+            .iter()
+            .any(|defaults| {
+                [&defaults.grpc, &defaults.wrpc_borsh, &defaults.wrpc_json].contains(&address)
+            })
+    }
+
+    pub async fn load() -> Result<Self> {
+        let storage = Storage::try_new(NETWORK_DEFAULTS_FILENAME)?;
+        if storage.exists().await.unwrap_or(false) {
+            let text = workflow_store::fs::read_to_string(storage.filename()).await?;
+            Ok(toml::from_str(&text)?)
+        } else {
+            Ok(Self::default())
+        }
+    }
+}
+
+fn network_defaults_table() -> &'static Mutex<Arc<NetworkDefaultsTable>> {
+    static TABLE: OnceLock<Mutex<Arc<NetworkDefaultsTable>>> = OnceLock::new();
+    TABLE.get_or_init(|| Mutex::new(Arc::new(NetworkDefaultsTable::default())))
+}
+
+pub fn network_defaults() -> Arc<NetworkDefaultsTable> {
+    network_defaults_table().lock().unwrap().clone()
+}
+
 #[derive(Default, Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq)]
 #[serde(rename_all = "kebab-case")]
 pub enum NodeConnectionConfigKind {
@@ -259,6 +402,42 @@ impl NodeConnectionConfigKind {
     }
 }
 
+/// Method used to stop the integrated daemon.
+#[derive(Default, Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum TondidTerminationMethod {
+    /// Request shutdown with SIGTERM, falling back to SIGKILL after a timeout
+    #[default]
+    Sigterm,
+    Sigkill,
+}
+
+impl TondidTerminationMethod {
+    pub fn iter() -> impl Iterator<Item = &'static TondidTerminationMethod> {
+        [TondidTerminationMethod::Sigterm, TondidTerminationMethod::Sigkill].iter()
+    }
+
+    pub fn describe(&self) -> &str {
+        match self {
+            TondidTerminationMethod::Sigterm => i18n("Graceful shutdown, the node is killed if it does not exit within the timeout (SIGTERM is not available on Windows)"),
+            TondidTerminationMethod::Sigkill => i18n("Kill the node immediately (may interrupt database writes)"),
+        }
+    }
+}
+
+impl std::fmt::Display for TondidTerminationMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TondidTerminationMethod::Sigterm => write!(f, "{}", i18n("Graceful")),
+            TondidTerminationMethod::Sigkill => write!(f, "{}", i18n("Kill")),
+        }
+    }
+}
+
+fn default_termination_timeout() -> u64 {
+    30
+}
+
 #[derive(Default, Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq)]
 #[serde(rename_all = "kebab-case")]
 pub enum NodeMemoryScale {
@@ -385,113 +564,109 @@ pub struct NodeSettings {
     pub tondid_daemon_storage_folder: String,
     #[serde(default)]
     pub devnet_custom_url: Option<String>,
+    /// Additional gRPC endpoints per network, tried in order
+    /// when the primary endpoint drops or falls out of sync.
+    #[serde(default)]
+    pub failover_endpoints: HashMap<Network, Vec<String>>,
+    #[serde(default)]
+    pub tondid_daemon_termination: TondidTerminationMethod,
+    /// Seconds to wait for a graceful shutdown before killing the daemon
+    #[serde(default = "default_termination_timeout")]
+    pub tondid_daemon_termination_timeout: u64,
 }
 
 impl Default for NodeSettings {
     fn default() -> Self {
-        // 使用带端口的默认配置，便于连接本地或远程节点
-        let default_grpc_interface = if cfg!(target_arch = "wasm32") {
-            // Web版本使用本地地址
-            NetworkInterfaceConfig {
-                kind: NetworkInterfaceKind::Custom,
-                custom: "127.0.0.1:16610".parse().unwrap(),
-            }
-        } else {
-            // 桌面版本，Devnet默认使用远程节点
-            NetworkInterfaceConfig {
-                kind: NetworkInterfaceKind::Custom,
-                custom: "8.210.45.192:16610".parse().unwrap(), // 默认连接到远程devnet节点
-            }
-        };
-        
-
+        let network = Network::Devnet;
+        let defaults = network_defaults();
+        let defaults = defaults.get(network);
 
-        let settings = Self {
+        Self {
             connection_config_kind: NodeConnectionConfigKind::Custom,  // 改为Custom以启用自定义RPC配置
             rpc_kind: RpcKind::Grpc,  // 默认使用gRPC而不是Wrpc
             wrpc_url: "127.0.0.1".to_string(),
             wrpc_encoding: WrpcEncoding::Borsh,
             enable_wrpc_borsh: false,
-            wrpc_borsh_network_interface: NetworkInterfaceConfig::default(),
+            wrpc_borsh_network_interface: defaults.wrpc_borsh_interface(),
             enable_wrpc_json: false,
-            wrpc_json_network_interface: NetworkInterfaceConfig::default(),
+            wrpc_json_network_interface: defaults.wrpc_json_interface(),
             enable_grpc: true,  // 默认启用gRPC
-            grpc_network_interface: default_grpc_interface,
+            grpc_network_interface: defaults.grpc_interface(),
             enable_upnp: true,
             memory_scale: NodeMemoryScale::default(),
-            network: Network::Devnet,  // 改为Devnet
+            network,
             node_kind: TondidNodeKind::Remote,  // 改为Remote以连接远程节点
             tondid_daemon_binary: String::default(),
             tondid_daemon_args: String::default(),
             tondid_daemon_args_enable: false,
             tondid_daemon_storage_folder_enable: false,
             tondid_daemon_storage_folder: String::default(),
-            devnet_custom_url: Some("https://8.210.45.192/".to_string()),  // 设置远程devnet地址
-        };
-        
-
-        
-        settings
+            devnet_custom_url: None,
+            failover_endpoints: HashMap::new(),
+            tondid_daemon_termination: TondidTerminationMethod::default(),
+            tondid_daemon_termination_timeout: default_termination_timeout(),
+        }
     }
 }
 
 impl NodeSettings {
     /// 根据网络类型自动更新端口配置
+    ///
+    /// Only endpoints that still hold one of the network defaults are
+    /// updated; custom endpoints configured by the user are preserved.
     pub fn update_ports_for_network(&mut self) {
-        println!("[UPDATE PORTS DEBUG] update_ports_for_network 被调用");
-        println!("[UPDATE PORTS DEBUG] self.network: {:?}", self.network);
-        println!("[UPDATE PORTS DEBUG] 调用前 grpc_network_interface: {:?}", self.grpc_network_interface);
-        
-        match self.network {
-            Network::Mainnet => {
-                // Mainnet: gRPC 16110, wRPC 17110
-                if self.enable_grpc {
-                    self.grpc_network_interface.kind = NetworkInterfaceKind::Custom;
-                    self.grpc_network_interface.custom = "127.0.0.1:16110".parse().unwrap();
-                }
-                if self.enable_wrpc_borsh {
-                    self.wrpc_borsh_network_interface.kind = NetworkInterfaceKind::Custom;
-                    self.wrpc_borsh_network_interface.custom = "127.0.0.1:17110".parse().unwrap();
-                }
-                if self.enable_wrpc_json {
-                    self.wrpc_json_network_interface.kind = NetworkInterfaceKind::Custom;
-                    self.wrpc_json_network_interface.custom = "127.0.0.1:18110".parse().unwrap();
-                }
-            }
-            Network::Testnet => {
-                // Testnet: gRPC 16210, wRPC 17210
-                if self.enable_grpc {
-                    self.grpc_network_interface.kind = NetworkInterfaceKind::Custom;
-                    self.grpc_network_interface.custom = "127.0.0.1:16210".parse().unwrap();
-                }
-                if self.enable_wrpc_borsh {
-                    self.wrpc_borsh_network_interface.kind = NetworkInterfaceKind::Custom;
-                    self.wrpc_borsh_network_interface.custom = "127.0.0.1:17210".parse().unwrap();
-                }
-                if self.enable_wrpc_json {
-                    self.wrpc_json_network_interface.kind = NetworkInterfaceKind::Custom;
-                    self.wrpc_json_network_interface.custom = "127.0.0.1:18210".parse().unwrap();
-                }
-            }
-            Network::Devnet => {
-                // Devnet: gRPC 16610, wRPC 17610
-                if self.network == Network::Devnet {
-                    // 对于devnet，默认连接到远程节点
-                    self.grpc_network_interface.kind = NetworkInterfaceKind::Custom;
-                    self.grpc_network_interface.custom = "8.210.45.192:16610".parse().unwrap();
-                    
-                    if self.enable_wrpc_borsh {
-                        self.wrpc_borsh_network_interface.kind = NetworkInterfaceKind::Custom;
-                        self.wrpc_borsh_network_interface.custom = "8.210.45.192:17610".parse().unwrap();
-                    }
-                    if self.enable_wrpc_json {
-                        self.wrpc_json_network_interface.kind = NetworkInterfaceKind::Custom;
-                        self.wrpc_json_network_interface.custom = "8.210.45.192:18610".parse().unwrap();
-                    }
-                }
+        let table = network_defaults();
+        let defaults = table.get(self.network);
+
+        let update = |interface: &mut NetworkInterfaceConfig, default: NetworkInterfaceConfig| {
+            if interface.kind == NetworkInterfaceKind::Custom && table.is_default(&interface.custom) {
+                *interface = default;
             }
+        };
 
+        if self.enable_grpc {
+            update(&mut self.grpc_network_interface, defaults.grpc_interface());
         }
+        if self.enable_wrpc_borsh {
+            update(&mut self.wrpc_borsh_network_interface, defaults.wrpc_borsh_interface());
+        }
+        if self.enable_wrpc_json {
+            update(&mut self.wrpc_json_network_interface, defaults.wrpc_json_interface());
+        }
+    }
+
+    /// Parse a failover endpoint entry (`host:port`, optionally
+    /// prefixed with `grpc://`).
+    pub fn parse_failover_endpoint(endpoint: &str) -> Result<NetworkInterfaceConfig> {
+        let custom = endpoint
+            .trim()
+            .trim_start_matches("grpc://")
+            .parse::<ContextualNetAddress>()
+            .map_err(|err| Error::custom(format!("Invalid endpoint `{endpoint}`: {err}")))?;
+        Ok(NetworkInterfaceConfig {
+            kind: NetworkInterfaceKind::Custom,
+            custom,
+        })
+    }
+
+    /// Failover gRPC endpoints of the current network, in order of preference.
+    pub fn grpc_failover_endpoints(&self) -> Vec<NetworkInterfaceConfig> {
+        self.failover_endpoints
+            .get(&self.network)
+            .map(|endpoints| {
+                endpoints
+                    .iter()
+                    .filter(|endpoint| !endpoint.trim().is_empty())
+                    .filter_map(|endpoint| match Self::parse_failover_endpoint(endpoint) {
+                        Ok(endpoint) => Some(endpoint),
+                        Err(err) => {
+                            log_warn!("{}", err);
+                            None
+                        }
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
     }
 
     cfg_if! {
@@ -513,6 +688,7 @@ impl NodeSettings {
                     Some(true)
                 } else if self.enable_grpc != other.enable_grpc
                     || self.grpc_network_interface != other.grpc_network_interface
+                    || self.failover_endpoints != other.failover_endpoints
                     || self.wrpc_url != other.wrpc_url
                     || self.wrpc_encoding != other.wrpc_encoding
                     || self.enable_wrpc_json != other.enable_wrpc_json
@@ -528,6 +704,10 @@ impl NodeSettings {
                     Some(self.node_kind == TondidNodeKind::ExternalAsDaemon)
                 } else if self.devnet_custom_url != other.devnet_custom_url {
                     Some(true)
+                } else if self.tondid_daemon_termination != other.tondid_daemon_termination
+                    || self.tondid_daemon_termination_timeout != other.tondid_daemon_termination_timeout
+                {
+                    Some(false)
                 } else {
                     None
                 }
@@ -557,6 +737,97 @@ impl NodeSettings {
     }
 }
 
+/// A named set of node connection parameters that can be saved
+/// and switched to from the status bar.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct NodeProfile {
+    pub name: String,
+    pub network: Network,
+    pub node_kind: TondidNodeKind,
+    /// Profiles saved before the connection kind was recorded
+    /// always referred to a custom endpoint.
+    #[serde(default = "NodeProfile::default_connection_config_kind")]
+    pub connection_config_kind: NodeConnectionConfigKind,
+    pub rpc_kind: RpcKind,
+    /// Custom endpoint, only used with [`NodeConnectionConfigKind::Custom`]
+    pub url: String,
+    pub wrpc_encoding: WrpcEncoding,
+}
+
+impl NodeProfile {
+    fn default_connection_config_kind() -> NodeConnectionConfigKind {
+        NodeConnectionConfigKind::Custom
+    }
+
+    pub fn from_node_settings(name: &str, settings: &NodeSettings) -> Self {
+        let url = match (settings.connection_config_kind, &settings.rpc_kind) {
+            (NodeConnectionConfigKind::Custom, RpcKind::Grpc) => settings.grpc_network_interface.to_string(),
+            (NodeConnectionConfigKind::Custom, RpcKind::Wrpc) => settings.wrpc_url.clone(),
+            _ => String::new(),
+        };
+
+        Self {
+            name: name.to_string(),
+            network: settings.network,
+            node_kind: settings.node_kind,
+            connection_config_kind: settings.connection_config_kind,
+            rpc_kind: settings.rpc_kind.clone(),
+            url,
+            wrpc_encoding: settings.wrpc_encoding,
+        }
+    }
+
+    /// Produce a copy of `settings` with this profile applied. The
+    /// profile URL is validated up front so that an invalid profile
+    /// never results in partially updated settings.
+    pub fn apply(&self, settings: &NodeSettings) -> Result<NodeSettings> {
+        let mut node = settings.clone();
+
+        node.network = self.network;
+        node.node_kind = self.node_kind;
+        node.rpc_kind = self.rpc_kind.clone();
+        node.wrpc_encoding = self.wrpc_encoding;
+
+        let url = self.url.trim();
+        if node.node_kind != TondidNodeKind::Remote {
+            node.update_ports_for_network();
+        } else if self.connection_config_kind != NodeConnectionConfigKind::Custom {
+            node.connection_config_kind = self.connection_config_kind;
+        } else if url.is_empty() {
+            return Err(Error::custom(i18n("The profile does not specify a node address")));
+        } else {
+            node.connection_config_kind = NodeConnectionConfigKind::Custom;
+            match self.rpc_kind {
+                RpcKind::Grpc => {
+                    let custom = url
+                        .trim_start_matches("grpc://")
+                        .parse::<ContextualNetAddress>()
+                        .map_err(|err| {
+                            Error::custom(format!("Invalid gRPC address `{url}`: {err}"))
+                        })?;
+                    node.enable_grpc = true;
+                    node.grpc_network_interface = NetworkInterfaceConfig {
+                        kind: NetworkInterfaceKind::Custom,
+                        custom,
+                    };
+                }
+                RpcKind::Wrpc => {
+                    TondiRpcClient::parse_url(
+                        url.to_string(),
+                        self.wrpc_encoding,
+                        self.network.into(),
+                    )
+                    .map_err(|err| Error::custom(format!("Invalid wRPC URL `{url}`: {err}")))?;
+                    node.wrpc_url = url.to_string();
+                }
+            }
+        }
+
+        Ok(node)
+    }
+}
+
 impl RpcConfig {
     pub fn from_node_settings(settings: &NodeSettings, _options: Option<RpcOptions>) -> Self {
         match settings.connection_config_kind {
@@ -568,6 +839,7 @@ impl RpcConfig {
                 },
                 RpcKind::Grpc => RpcConfig::Grpc {
                     url: Some(settings.grpc_network_interface.clone()),
+                    failover: settings.grpc_failover_endpoints(),
                 },
             },
             NodeConnectionConfigKind::PublicServerCustom
@@ -580,6 +852,64 @@ impl RpcConfig {
     }
 }
 
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum MetricAlertCondition {
+    Above,
+    Below,
+    /// The metric value has not changed (e.g. a stalled sync)
+    Unchanged,
+}
+
+impl MetricAlertCondition {
+    pub fn iter() -> impl Iterator<Item = &'static MetricAlertCondition> {
+        [
+            MetricAlertCondition::Above,
+            MetricAlertCondition::Below,
+            MetricAlertCondition::Unchanged,
+        ]
+        .iter()
+    }
+
+    pub fn has_threshold(&self) -> bool {
+        !matches!(self, MetricAlertCondition::Unchanged)
+    }
+}
+
+impl std::fmt::Display for MetricAlertCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MetricAlertCondition::Above => write!(f, "{}", i18n("Above")),
+            MetricAlertCondition::Below => write!(f, "{}", i18n("Below")),
+            MetricAlertCondition::Unchanged => write!(f, "{}", i18n("Unchanged")),
+        }
+    }
+}
+
+/// User-defined alert raised when `metric` satisfies `condition`
+/// for at least `duration` seconds.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MetricAlertRule {
+    pub enable: bool,
+    pub metric: Metric,
+    pub condition: MetricAlertCondition,
+    pub threshold: f64,
+    pub duration: u64,
+}
+
+impl Default for MetricAlertRule {
+    fn default() -> Self {
+        Self {
+            enable: true,
+            metric: Metric::NodeActivePeers,
+            condition: MetricAlertCondition::Below,
+            threshold: 3.0,
+            duration: 60,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub struct MetricsSettings {
@@ -588,6 +918,8 @@ pub struct MetricsSettings {
     pub graph_range_from: isize,
     pub graph_range_to: isize,
     pub disabled: AHashSet<Metric>,
+    #[serde(default)]
+    pub alerts: Vec<MetricAlertRule>,
 }
 
 impl Default for MetricsSettings {
@@ -598,6 +930,7 @@ impl Default for MetricsSettings {
             graph_range_from: -15 * 60,
             graph_range_to: 0,
             disabled: AHashSet::default(),
+            alerts: Vec::new(),
         }
     }
 }
@@ -612,6 +945,15 @@ pub struct UserInterfaceSettings {
     pub balance_padding: bool,
     #[serde(default)]
     pub disable_frame: bool,
+    /// Show fiat values next to TONDI amounts
+    #[serde(default)]
+    pub fiat: bool,
+    #[serde(default = "default_fiat_currency")]
+    pub fiat_currency: String,
+}
+
+fn default_fiat_currency() -> String {
+    "usd".to_string()
 }
 
 impl Default for UserInterfaceSettings {
@@ -631,6 +973,8 @@ impl Default for UserInterfaceSettings {
             metrics: MetricsSettings::default(),
             balance_padding: true,
             disable_frame: true,
+            fiat: false,
+            fiat_currency: default_fiat_currency(),
         }
     }
 }
@@ -707,6 +1051,67 @@ impl EstimatorSettings {
     }
 }
 
+#[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct PrometheusSettings {
+    pub enable: bool,
+    pub listen: String,
+}
+
+impl Default for PrometheusSettings {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            listen: "127.0.0.1:9464".to_string(),
+        }
+    }
+}
+
+/// Source of market data used by the market monitor
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum MarketDataProviderKind {
+    #[default]
+    CoinGecko,
+    CoinMarketCap,
+    /// Custom endpoint returning `{ "<currency>": { "price": <f64>, ... } }`
+    HttpJson { url: String },
+    /// Fixed price per currency, useful on testnets
+    FixedRate { rates: BTreeMap<String, f64> },
+}
+
+impl MarketDataProviderKind {
+    pub fn describe(&self) -> &'static str {
+        match self {
+            MarketDataProviderKind::CoinGecko => "CoinGecko",
+            MarketDataProviderKind::CoinMarketCap => "CoinMarketCap",
+            MarketDataProviderKind::HttpJson { .. } => "Custom HTTP JSON endpoint",
+            MarketDataProviderKind::FixedRate { .. } => "Fixed rate",
+        }
+    }
+}
+
+pub const MARKET_MONITOR_MIN_INTERVAL: u64 = 10;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct MarketMonitorSettings {
+    pub provider: MarketDataProviderKind,
+    /// Polling interval in seconds
+    pub interval: u64,
+    pub currencies: Vec<String>,
+}
+
+impl Default for MarketMonitorSettings {
+    fn default() -> Self {
+        Self {
+            provider: MarketDataProviderKind::default(),
+            interval: 60,
+            currencies: vec!["usd".to_string(), "btc".to_string()],
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub struct Settings {
@@ -719,10 +1124,18 @@ pub struct Settings {
     #[serde(default)]
     pub estimator: EstimatorSettings,
     pub node: NodeSettings,
+    #[serde(default)]
+    pub node_profiles: Vec<NodeProfile>,
+    #[serde(default)]
+    pub active_node_profile: Option<String>,
     pub user_interface: UserInterfaceSettings,
     pub language_code: String,
     pub update_monitor: bool,
     pub market_monitor: bool,
+    #[serde(default)]
+    pub market: MarketMonitorSettings,
+    #[serde(default)]
+    pub prometheus: PrometheusSettings,
     pub update_check_timeout: u64, // 更新检查超时时间（秒）
     pub update_check_retries: u32, // 更新检查重试次数
     pub update_check_interval: u64, // 更新检查间隔（秒）
@@ -742,10 +1155,14 @@ impl Default for Settings {
             developer: DeveloperSettings::default(),
             estimator: EstimatorSettings::default(),
             node: NodeSettings::default(),
+            node_profiles: Vec::new(),
+            active_node_profile: None,
             user_interface: UserInterfaceSettings::default(),
             language_code: "en".to_string(),
             update_monitor: true,
             market_monitor: true,
+            market: MarketMonitorSettings::default(),
+            prometheus: PrometheusSettings::default(),
             update_check_timeout: 30, // 默认30秒超时
             update_check_retries: 3,  // 默认3次重试
             update_check_interval: 60 * 60 * 12, // 默认12小时检查一次
@@ -787,6 +1204,15 @@ impl Settings {
     pub async fn load() -> Result<Self> {
         use workflow_store::fs::read_json;
 
+        match NetworkDefaultsTable::load().await {
+            Ok(table) => {
+                *network_defaults_table().lock().unwrap() = Arc::new(table);
+            }
+            Err(err) => {
+                log_warn!("Unable to load network defaults: {}", err);
+            }
+        }
+
         let storage = storage()?;
         if storage.exists().await.unwrap_or(false) {
             match read_json::<Self>(storage.filename()).await {
@@ -853,8 +1279,50 @@ mod tests {
         // 测试 Devnet 端口
         settings.network = Network::Devnet;
         settings.update_ports_for_network();
-        assert_eq!(settings.grpc_network_interface.custom.to_string(), "8.210.45.192:16610");
-        assert_eq!(settings.wrpc_borsh_network_interface.custom.to_string(), "8.210.45.192:17610");
-        assert_eq!(settings.wrpc_json_network_interface.custom.to_string(), "8.210.45.192:18610");
+        assert_eq!(settings.grpc_network_interface.custom.to_string(), "127.0.0.1:16610");
+        assert_eq!(settings.wrpc_borsh_network_interface.custom.to_string(), "127.0.0.1:17610");
+        assert_eq!(settings.wrpc_json_network_interface.custom.to_string(), "127.0.0.1:18610");
+    }
+
+    #[test]
+    fn test_update_ports_preserves_custom_endpoints() {
+        let mut settings = NodeSettings::default();
+        settings.grpc_network_interface.custom = "10.0.0.5:16610".parse().unwrap();
+
+        settings.network = Network::Mainnet;
+        settings.update_ports_for_network();
+        assert_eq!(settings.grpc_network_interface.custom.to_string(), "10.0.0.5:16610");
+    }
+
+    #[test]
+    fn test_network_defaults_from_toml() {
+        let table: NetworkDefaultsTable = toml::from_str(
+            r#"
+            [devnet]
+            grpc = "10.0.0.5:16610"
+            wrpc-borsh = "10.0.0.5:17610"
+            wrpc-json = "10.0.0.5:18610"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(table.devnet.grpc.to_string(), "10.0.0.5:16610");
+        assert_eq!(table.mainnet, NetworkDefaultsTable::default().mainnet);
+    }
+
+    #[test]
+    fn test_node_profile_restores_connection_kind() {
+        let mut settings = NodeSettings {
+            node_kind: TondidNodeKind::Remote,
+            connection_config_kind: NodeConnectionConfigKind::PublicServerRandom,
+            wrpc_url: "ws://127.0.0.1:17110".to_string(),
+            ..Default::default()
+        };
+        let profile = NodeProfile::from_node_settings("public", &settings);
+        assert!(profile.url.is_empty());
+
+        settings.connection_config_kind = NodeConnectionConfigKind::Custom;
+        let node = profile.apply(&settings).unwrap();
+        assert_eq!(node.connection_config_kind, NodeConnectionConfigKind::PublicServerRandom);
     }
 }
@@ -0,0 +1,73 @@
+//! JSON files stored next to the wallet file as `<wallet>.<extension>`,
+//! holding user data that is not part of the wallet itself.
+
+use crate::imports::*;
+use futures::lock::Mutex as AsyncMutex;
+use serde::de::DeserializeOwned;
+use tondi_wallet_core::storage::local::storage::Storage;
+
+pub trait Sidecar: Default + Clone + Serialize + DeserializeOwned + Send + Sync + 'static {
+    /// Extension appended to the wallet filename.
+    const EXTENSION: &'static str;
+}
+
+fn storage<T: Sidecar>(wallet_filename: &str) -> Result<Storage> {
+    Ok(Storage::try_new(&format!("{wallet_filename}.{}", T::EXTENSION))?)
+}
+
+/// Load the sidecar file of the given wallet; a missing file yields the
+/// default (empty) data.
+pub async fn load<T: Sidecar>(wallet_filename: &str) -> Result<T> {
+    let storage = storage::<T>(wallet_filename)?;
+    if storage.exists().await.unwrap_or(false) {
+        Ok(workflow_store::fs::read_json::<T>(storage.filename()).await?)
+    } else {
+        Ok(T::default())
+    }
+}
+
+pub async fn store<T: Sidecar>(wallet_filename: &str, data: &T) -> Result<()> {
+    let storage = storage::<T>(wallet_filename)?;
+    storage.ensure_dir().await?;
+    workflow_store::fs::write_json(storage.filename(), data).await?;
+    Ok(())
+}
+
+/// Serializes sidecar writes. Each write stores the most recent pending
+/// snapshot of its file, so quick successive saves can not leave an older
+/// snapshot on disk.
+pub struct SidecarWriter<T: Sidecar> {
+    pending: Arc<Mutex<AHashMap<String, T>>>,
+    lock: Arc<AsyncMutex<()>>,
+}
+
+impl<T: Sidecar> Default for SidecarWriter<T> {
+    fn default() -> Self {
+        Self {
+            pending: Arc::new(Mutex::new(AHashMap::default())),
+            lock: Arc::new(AsyncMutex::new(())),
+        }
+    }
+}
+
+impl<T: Sidecar> SidecarWriter<T> {
+    /// Schedule `data` to be written next to the given wallet, reporting
+    /// failures prefixed with `error_caption`.
+    pub fn store(&self, wallet_filename: String, data: T, error_caption: String) {
+        self.pending.lock().unwrap().insert(wallet_filename.clone(), data);
+
+        let pending = self.pending.clone();
+        let lock = self.lock.clone();
+        spawn(async move {
+            let _guard = lock.lock().await;
+            // already written by a task that started after this one
+            let Some(data) = pending.lock().unwrap().remove(&wallet_filename) else {
+                return Ok(());
+            };
+            if let Err(err) = store(&wallet_filename, &data).await {
+                runtime().error(format!("{error_caption}: {err}"));
+            }
+            Ok(())
+        });
+    }
+}
@@ -1,4 +1,8 @@
 use crate::imports::*;
+use crate::runtime::services::tondi::DaemonShutdown;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::runtime::services::tondi::supervisor::SupervisorState;
+use crate::settings::RpcKind;
 use crate::sync::SyncStatus;
 // use tondi_metrics_core::MetricsSnapshot;
 
@@ -88,9 +92,84 @@ impl<'core> Status<'core> {
                     }
                 });
             }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            self.render_supervisor_badge(ui);
         });
     }
 
+    /// Restart / crash indicator of the supervised node daemon
+    #[cfg(not(target_arch = "wasm32"))]
+    fn render_supervisor_badge(&mut self, ui: &mut egui::Ui) {
+        use egui_phosphor::light::{ARROWS_CLOCKWISE, WARNING, WARNING_OCTAGON};
+
+        let tondi_service = runtime().tondi_service();
+        let (text, color) = {
+            let supervisor = tondi_service.supervisor();
+            let crashes = supervisor.crashes().count();
+            match supervisor.state() {
+                SupervisorState::Restarting { attempt, delay } => (
+                    format!(
+                        "{ARROWS_CLOCKWISE} {}",
+                        i18n_args(
+                            "Restarting node ({attempt}) in {seconds} sec",
+                            &[("attempt", attempt.to_string().as_str()), ("seconds", delay.as_secs().to_string().as_str())],
+                        )
+                    ),
+                    theme_color().warning_color,
+                ),
+                SupervisorState::GaveUp => (
+                    format!("{WARNING_OCTAGON} {}", i18n("Node stopped after repeated crashes")),
+                    theme_color().error_color,
+                ),
+                SupervisorState::Idle if crashes > 0 => (
+                    format!("{WARNING} {}", i18n_args("Crashes: {count}", &[("count", crashes.to_string().as_str())])),
+                    theme_color().warning_color,
+                ),
+                SupervisorState::Idle => return,
+            }
+        };
+
+        self.render_separator(ui);
+
+        PopupPanel::new(
+            PopupPanel::id(ui, "supervisor_crash_history"),
+            |ui| ui.add(Label::new(RichText::new(text).color(color)).sense(Sense::click())),
+            |ui, close| {
+                egui::ScrollArea::vertical()
+                    .id_salt("supervisor_crash_history_scroll")
+                    .max_height(320.)
+                    .show(ui, |ui| {
+                        let supervisor = tondi_service.supervisor();
+                        for crash in supervisor.crashes().rev() {
+                            let time = chrono::DateTime::from_timestamp_millis(crash.timestamp as i64)
+                                .map(|time| time.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M:%S").to_string())
+                                .unwrap_or_default();
+                            let code = crash.code.map(|code| code.to_string()).unwrap_or_else(|| i18n("none").to_string());
+                            CollapsingHeader::new(i18n_args("{time} - exit code {code}", &[("time", time.as_str()), ("code", code.as_str())]))
+                                .id_salt(crash.timestamp.to_bits())
+                                .default_open(false)
+                                .show(ui, |ui| {
+                                    for line in crash.log_tail.iter() {
+                                        ui.label(RichText::new(line).font(FontId::monospace(theme_style().node_log_font_size)));
+                                    }
+                                });
+                        }
+                    });
+
+                ui.separator();
+                if ui.button(i18n("Clear")).clicked() {
+                    tondi_service.supervisor().clear();
+                    *close = true;
+                }
+            },
+        )
+        .with_min_width(420.)
+        .with_caption(i18n("Node Crash History"))
+        .with_close_button(true)
+        .build(ui);
+    }
+
     fn render_peers(&self, ui: &mut egui::Ui, peers: Option<usize>) {
         let status_icon_size = theme_style().status_icon_size;
 
@@ -185,6 +264,57 @@ impl<'core> Status<'core> {
         }
     }
 
+    fn render_profile_selector(&mut self, ui: &mut Ui) {
+        use egui_phosphor::light::CHECK;
+
+        if self.settings().node_profiles.is_empty() {
+            return;
+        }
+
+        self.render_separator(ui);
+
+        let active = self.settings().active_node_profile.clone();
+        let label = active.clone().unwrap_or_else(|| i18n("Profiles").to_string());
+
+        if self.core.module().modal() {
+            ui.label(label);
+            return;
+        }
+
+        let response = ui.add(Label::new(RichText::new(label)).sense(Sense::click()));
+        let id = PopupPanel::id(ui, "node_profile_selector_popup");
+        PopupPanel::new(
+            id,
+            |_ui| response,
+            |ui, close| {
+                set_menu_style(ui.style_mut());
+
+                let names = self
+                    .settings()
+                    .node_profiles
+                    .iter()
+                    .map(|profile| profile.name.clone())
+                    .collect::<Vec<_>>();
+
+                names.into_iter().for_each(|name| {
+                    let text = if active.as_ref() == Some(&name) {
+                        format!("{name} {CHECK}")
+                    } else {
+                        name.clone()
+                    };
+
+                    if ui.button(text).clicked() {
+                        *close = true;
+                        self.core.apply_node_profile(&name);
+                    }
+                });
+            },
+        )
+        .with_min_width(100.0)
+        .with_above_or_below(AboveOrBelow::Above)
+        .build(ui);
+    }
+
     fn render_separator(&mut self, ui: &mut egui::Ui) {
         if self.device().desktop() {
             ui.separator();
@@ -192,6 +322,23 @@ impl<'core> Status<'core> {
             Separator::default().spacing(1.0).ui(ui);
         }
     }
+    fn render_daemon_shutdown(&mut self, ui: &mut egui::Ui, shutdown: &DaemonShutdown) {
+        ui.vertical(|ui| {
+            ui.add_space(2.);
+            ui.add(egui::Spinner::new());
+        });
+        ui.separator();
+        ui.label(i18n("Stopping node..."));
+
+        let elapsed = shutdown.elapsed.as_secs();
+        let timeout = shutdown.timeout.as_secs().max(1);
+        ui.add(
+            egui::ProgressBar::new(elapsed as f32 / timeout as f32)
+                .desired_width(120.)
+                .text(format!("{elapsed} / {timeout} sec")),
+        );
+    }
+
     fn render_connected_state(&mut self, ui: &mut egui::Ui, state: ConnectionStatus) {
         let status_area_width = ui.available_width() - 24.;
         let status_icon_size = theme_style().status_icon_size;
@@ -202,6 +349,11 @@ impl<'core> Status<'core> {
             ConnectionStatus::Disconnected => {
                 ui.add_space(left_padding);
 
+                if let Some(shutdown) = self.core.daemon_shutdown.clone() {
+                    self.render_daemon_shutdown(ui, &shutdown);
+                    return;
+                }
+
                 match self.settings().node.node_kind {
                     TondidNodeKind::Disable => {
                         ui.label(
@@ -232,8 +384,8 @@ impl<'core> Status<'core> {
                                             rpc_url
                                         ));
                                     } else {
-                                        // 对于devnet，直接使用配置的gRPC URL而不是wRPC URL
-                                        if settings.node.network == Network::Devnet {
+                                        // gRPC连接直接使用配置的gRPC URL而不是wRPC URL
+                                        if settings.node.rpc_kind == RpcKind::Grpc {
                                             let grpc_url = format!("grpc://{}", settings.node.grpc_network_interface);
                                             ui.label(format!(
                                                 "{} {} ...",
@@ -347,6 +499,8 @@ impl<'core> Status<'core> {
                     }
                 }
 
+                self.render_profile_selector(ui);
+
                 if !self.device().single_pane() {
                     module.status_bar(self.core, ui);
                 }
@@ -386,6 +540,7 @@ impl<'core> Status<'core> {
                 self.render_connection_selector(ui);
                 self.render_separator(ui);
                 self.render_network_selector(ui);
+                self.render_profile_selector(ui);
 
                 if !self.device().mobile() {
                     self.render_separator(ui);
@@ -419,6 +574,7 @@ impl<'core> Status<'core> {
                         self.render_connection_selector(ui);
                         ui.separator();
                         self.render_network_selector(ui);
+                        self.render_profile_selector(ui);
 
                         if !self.device().single_pane() {
                             ui.separator();
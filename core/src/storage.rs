@@ -8,6 +8,7 @@ pub struct StorageFolder {
     pub folder_size: u64,
     pub folder_size_string: String,
     pub confirm_deletion: bool,
+    pub confirm_reset: bool,
 }
 
 impl Ord for StorageFolder {
@@ -46,10 +47,128 @@ impl StorageUpdateOptions {
     }
 }
 
+/// Free space remaining on a volume below which a warning is shown
+const DISK_SPACE_WARNING_RATIO: f64 = 0.1;
+
+#[derive(Debug, Clone, Copy)]
+pub struct DiskSpace {
+    pub available: u64,
+    pub total: u64,
+}
+
+impl DiskSpace {
+    pub fn is_nearly_full(&self) -> bool {
+        (self.available as f64) < self.total as f64 * DISK_SPACE_WARNING_RATIO
+    }
+}
+
+/// State of a data folder move started by [`Storage::relocate`]
+#[derive(Debug, Clone)]
+pub enum StorageRelocation {
+    Moving { target: PathBuf },
+    Complete { target: PathBuf },
+    Failed { error: String },
+}
+
 #[derive(Default, Clone)]
 pub struct Storage {
     pub folders: Arc<Mutex<Vec<StorageFolder>>>,
     pub storage_root: Arc<Mutex<Option<PathBuf>>>,
+    pub disk_space: Arc<Mutex<Option<DiskSpace>>>,
+    pub relocation: Arc<Mutex<Option<StorageRelocation>>>,
+}
+
+/// Move a directory, copying it if it can not be renamed (e.g. to another volume).
+/// A partially copied destination is removed if the copy fails.
+#[cfg(not(target_arch = "wasm32"))]
+fn move_dir(from: &std::path::Path, to: &std::path::Path) -> std::io::Result<()> {
+    if std::fs::rename(from, to).is_ok() {
+        return Ok(());
+    }
+
+    let copy = || -> std::io::Result<()> {
+        for entry in walkdir::WalkDir::new(from) {
+            let entry = entry?;
+            let target = to.join(entry.path().strip_prefix(from).map_err(std::io::Error::other)?);
+            if entry.file_type().is_dir() {
+                std::fs::create_dir_all(&target)?;
+            } else {
+                std::fs::copy(entry.path(), &target)?;
+            }
+        }
+        Ok(())
+    };
+
+    if let Err(err) = copy() {
+        if let Err(err) = std::fs::remove_dir_all(to) {
+            log_error!("Unable to remove partial copy {}: {err}", to.display());
+        }
+        return Err(err);
+    }
+
+    // the data is complete at the destination at this point
+    if let Err(err) = std::fs::remove_dir_all(from) {
+        log_warn!("Unable to remove {} after moving it: {err}", from.display());
+    }
+    Ok(())
+}
+
+/// Move the network data `folders` into `target`. If any folder can not be
+/// moved, the folders moved so far are moved back so that the node data is
+/// never split across two data folders.
+#[cfg(not(target_arch = "wasm32"))]
+fn move_folders(source: &std::path::Path, folders: &[PathBuf], target: &std::path::Path) -> std::io::Result<()> {
+    if target.starts_with(source) {
+        return Err(std::io::Error::other(i18n("The destination is inside the current data folder")));
+    }
+
+    std::fs::create_dir_all(target)?;
+
+    let mut moved = vec![];
+    let result = folders.iter().try_for_each(|path| {
+        let Some(name) = path.file_name() else {
+            return Ok(());
+        };
+        let destination = target.join(name);
+        if destination.exists() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::AlreadyExists,
+                format!("{} {}", i18n("Destination already exists:"), destination.display()),
+            ));
+        }
+        move_dir(path, &destination)?;
+        moved.push((path.clone(), destination));
+        Ok(())
+    });
+
+    if result.is_err() {
+        for (path, destination) in moved.into_iter().rev() {
+            if let Err(err) = move_dir(&destination, &path) {
+                log_error!("Unable to move {} back to {}: {err}", destination.display(), path.display());
+            }
+        }
+    }
+
+    result
+}
+
+/// Space of the volume holding `path`
+#[cfg(not(target_arch = "wasm32"))]
+fn disk_space(path: &std::path::Path) -> Option<DiskSpace> {
+    use sysinfo::{DiskExt, System, SystemExt};
+
+    let path = path.canonicalize().ok()?;
+    let mut system = System::new();
+    system.refresh_disks_list();
+    system
+        .disks()
+        .iter()
+        .filter(|disk| path.starts_with(disk.mount_point()))
+        .max_by_key(|disk| disk.mount_point().as_os_str().len())
+        .map(|disk| DiskSpace {
+            available: disk.available_space(),
+            total: disk.total_space(),
+        })
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -90,6 +209,8 @@ impl Storage {
                 task::sleep(delay).await;
             }
 
+            *this.disk_space.lock().unwrap() = disk_space(&rusty_tondi_app_dir);
+
             let paths = std::fs::read_dir(rusty_tondi_app_dir).unwrap();
             for path in paths {
                 let path = path?.path();
@@ -129,6 +250,7 @@ impl Storage {
         if let Some(folder) = folders.iter_mut().find(|folder| folder.network == network) {
             folder.folder_size = folder_size;
             folder.folder_size_string = folder_size_string;
+            folder.path = path;
         } else {
             folders.push(StorageFolder {
                 name: network.to_string().to_uppercase(),
@@ -137,6 +259,7 @@ impl Storage {
                 folder_size,
                 folder_size_string,
                 confirm_deletion: false,
+                confirm_reset: false,
             });
 
             folders.sort();
@@ -180,6 +303,61 @@ impl Storage {
         });
     }
 
+    /// Move all network data folders to `target`; the node must not be running.
+    pub fn relocate(&self, target: PathBuf) {
+        let source = self.storage_root();
+        let folders = self
+            .folders
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|folder| folder.path.clone())
+            .collect::<Vec<_>>();
+
+        *self.relocation.lock().unwrap() = Some(StorageRelocation::Moving { target: target.clone() });
+
+        let this = self.clone();
+        spawn(async move {
+            // copying the node database can take a long time, so keep it off the async workers
+            let result = tokio::task::spawn_blocking({
+                let target = target.clone();
+                move || move_folders(&source, &folders, &target)
+            })
+            .await
+            .map_err(std::io::Error::other)
+            .and_then(|result| result);
+
+            let relocation = match result {
+                Ok(()) => StorageRelocation::Complete { target },
+                Err(err) => StorageRelocation::Failed { error: err.to_string() },
+            };
+            *this.relocation.lock().unwrap() = Some(relocation);
+            runtime().request_repaint();
+
+            Ok(())
+        });
+    }
+
+    fn render_disk_space(&self, ui: &mut Ui) {
+        use tondi_metrics_core::data::as_data_size;
+
+        if let Some(disk_space) = *self.disk_space.lock().unwrap() {
+            let text = i18n_args(
+                "Free space: {available} of {total}",
+                &[
+                    ("available", as_data_size(disk_space.available as f64, true).as_str()),
+                    ("total", as_data_size(disk_space.total as f64, true).as_str()),
+                ],
+            );
+            if disk_space.is_nearly_full() {
+                ui.colored_label(theme_color().warning_color, format!("{} {text}", egui_phosphor::light::WARNING));
+                ui.colored_label(theme_color().warning_color, i18n("The volume holding the node data is nearly full"));
+            } else {
+                ui.label(text);
+            }
+        }
+    }
+
     pub fn render(&self, ui: &mut Ui) {
         let folders = self.folders.lock().unwrap();
         if !folders.is_empty() {
@@ -199,6 +377,7 @@ impl Storage {
                                     network.to_string().to_uppercase()
                                 ));
                             }
+                            self.render_disk_space(ui);
                         });
                     });
             });
@@ -209,6 +388,26 @@ impl Storage {
         let mut folders = self.folders.lock().unwrap();
         for folder in folders.iter_mut() {
             folder.confirm_deletion = false;
+            folder.confirm_reset = false;
+        }
+    }
+
+    /// Apply a completed data folder move to the node settings,
+    /// returning `true` if the settings have been changed.
+    pub fn update_relocation(&self, core: &mut Core) -> bool {
+        let mut relocation = self.relocation.lock().unwrap();
+        if let Some(StorageRelocation::Complete { target }) = relocation.as_ref() {
+            let target = target.to_string_lossy().to_string();
+            runtime().notify(UserNotification::success(i18n_args("Node data moved to {path}", &[("path", target.as_str())])));
+            core.settings.node.tondid_daemon_storage_folder_enable = true;
+            core.settings.node.tondid_daemon_storage_folder = target;
+            core.store_settings();
+            relocation.take();
+            drop(relocation);
+            self.track_storage_root(Some(core.settings.node.tondid_daemon_storage_folder.as_str()));
+            true
+        } else {
+            false
         }
     }
 
@@ -220,21 +419,58 @@ impl Storage {
                 .default_open(false)
                 .show(ui, |ui| {
                     ui.vertical(|ui| {
+                        let is_local = core.settings.node.node_kind.is_local();
+
+                        ui.label(self.storage_root().display().to_string());
+                        self.render_disk_space(ui);
+
+                        let relocation = self.relocation.lock().unwrap().clone();
+                        let is_relocating = matches!(relocation, Some(StorageRelocation::Moving { .. }));
+                        match relocation {
+                            Some(StorageRelocation::Moving { target }) => {
+                                ui.horizontal(|ui| {
+                                    ui.spinner();
+                                    ui.label(i18n_args("Moving node data to {path}...", &[("path", target.display().to_string().as_str())]));
+                                });
+                            }
+                            Some(StorageRelocation::Failed { error }) => {
+                                ui.colored_label(theme_color().error_color, format!("{}: {error}", i18n("Unable to move node data")));
+                                if ui.medium_button(i18n("Dismiss")).clicked() {
+                                    self.relocation.lock().unwrap().take();
+                                }
+                            }
+                            _ => {
+                                if ui.medium_button_enabled(!is_local, i18n("Move Data Folder")).clicked() {
+                                    if let Some(target) = rfd::FileDialog::new().pick_folder() {
+                                        self.relocate(target);
+                                    }
+                                }
+                                if is_local {
+                                    ui.label(i18n("Please set node to 'Disabled' to move the data folder"));
+                                }
+                            }
+                        }
+
+                        ui.add_space(4.);
+
                         for folder in folders.iter_mut() {
-                            let StorageFolder { network, folder_size_string, path, confirm_deletion, .. } = folder;
+                            let StorageFolder { network, folder_size_string, path, confirm_deletion, confirm_reset, .. } = folder;
 
                             CollapsingHeader::new(format!("{}: {folder_size_string}", network.to_string().to_uppercase()))
                             .default_open(false)
                             .show(ui, |ui| {
-                                let is_running = core.settings.node.network == *network && core.settings.node.node_kind.is_local();
+                                let is_running = core.settings.node.network == *network && is_local;
 
                                 ui.horizontal(|ui|{
                                     if ui.medium_button(i18n("Open Data Folder")).clicked() {
-                                        if let Err(err) = open::that(path) {
+                                        if let Err(err) = open::that(&*path) {
                                             runtime().error(format!("Error opening folder: {:?}", err));
                                         }
                                     }
-                                    if ui.medium_button_enabled(!is_running && !*confirm_deletion, i18n("Delete Data Folder")).clicked() {
+                                    if ui.medium_button_enabled(!is_relocating && !*confirm_deletion && !*confirm_reset, i18n("Reset Database")).clicked() {
+                                        *confirm_reset = true;
+                                    }
+                                    if ui.medium_button_enabled(!is_running && !*confirm_deletion && !*confirm_reset, i18n("Delete Data Folder")).clicked() {
                                         *confirm_deletion = true;
                                     }
                                 });
@@ -244,6 +480,24 @@ impl Storage {
                                     ui.label(i18n("Please set node to 'Disabled' to delete the data folder"));
                                 }
 
+                                if *confirm_reset {
+                                    ui.add_sized(vec2(260.,4.), Separator::default());
+                                    ui.label(i18n("This action will erase the Tondi database; the node will resynchronize from the network"));
+                                    if is_running {
+                                        ui.label(i18n("The node will be restarted"));
+                                    }
+                                    ui.label("");
+                                    ui.colored_label(theme_color().alert_color, i18n("Please Confirm Database Reset"));
+                                    if let Some(response) = ui.confirm_medium_apply_cancel(Align::Min) {
+                                        if matches!(response, Confirm::Ack) {
+                                            let node_settings = is_running.then(|| core.settings.node.clone());
+                                            runtime().tondi_service().reset_database(*network, path.clone(), node_settings);
+                                        }
+                                        *confirm_reset = false;
+                                    }
+                                    ui.add_sized(vec2(260.,4.), Separator::default());
+                                }
+
                                 if *confirm_deletion {
                                     ui.add_sized(vec2(260.,4.), Separator::default());
                                     ui.label(i18n("This action will erase Tondi database and logs"));